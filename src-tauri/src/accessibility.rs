@@ -0,0 +1,98 @@
+use serde::Serialize;
+use tauri::AppHandle;
+
+use crate::store::emit_to_main;
+
+// Announcements are short confirmations, not content
+const MAX_ANNOUNCEMENT_LEN: usize = 300;
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Announcement {
+    message: String,
+    /// True when the OS screen reader was already notified natively, so the
+    /// webview must not repeat it through its live region
+    spoken_natively: bool,
+}
+
+/// Announces a background action to screen-reader users.
+///
+/// On macOS this posts NSAccessibilityAnnouncementRequestedNotification. On
+/// other platforms the webview's live region speaks the `accessibility-announcement`
+/// event instead.
+pub fn announce(app: &AppHandle, message: &str) {
+    let spoken_natively = post_native(app, message);
+
+    let payload = Announcement {
+        message: message.to_string(),
+        spoken_natively,
+    };
+    emit_to_main(app, "accessibility-announcement", payload);
+}
+
+#[cfg(target_os = "macos")]
+fn post_native(app: &AppHandle, message: &str) -> bool {
+    use std::ffi::CString;
+
+    use objc2::runtime::AnyObject;
+    use objc2::{class, msg_send};
+
+    #[link(name = "AppKit", kind = "framework")]
+    extern "C" {
+        static NSAccessibilityAnnouncementRequestedNotification: *mut AnyObject;
+        static NSAccessibilityAnnouncementKey: *mut AnyObject;
+        static NSAccessibilityPriorityKey: *mut AnyObject;
+        fn NSAccessibilityPostNotificationWithUserInfo(
+            element: *mut AnyObject,
+            notification: *mut AnyObject,
+            user_info: *mut AnyObject,
+        );
+    }
+
+    // NSAccessibilityPriorityHigh
+    const PRIORITY_HIGH: isize = 90;
+
+    let Ok(text) = CString::new(message) else {
+        return false;
+    };
+
+    // AppKit must be called from the main thread
+    app.run_on_main_thread(move || unsafe {
+        let ns_app: *mut AnyObject = msg_send![class!(NSApplication), sharedApplication];
+        let ns_text: *mut AnyObject = msg_send![class!(NSString), stringWithUTF8String: text.as_ptr()];
+        let priority: *mut AnyObject = msg_send![class!(NSNumber), numberWithInteger: PRIORITY_HIGH];
+        let keys = [NSAccessibilityAnnouncementKey, NSAccessibilityPriorityKey];
+        let values = [ns_text, priority];
+        let user_info: *mut AnyObject = msg_send![
+            class!(NSDictionary),
+            dictionaryWithObjects: values.as_ptr(),
+            forKeys: keys.as_ptr(),
+            count: keys.len()
+        ];
+        NSAccessibilityPostNotificationWithUserInfo(
+            ns_app,
+            NSAccessibilityAnnouncementRequestedNotification,
+            user_info,
+        );
+    })
+    .is_ok()
+}
+
+#[cfg(not(target_os = "macos"))]
+fn post_native(_app: &AppHandle, _message: &str) -> bool {
+    false
+}
+
+/// Lets the webview request an announcement for actions it performs itself
+#[tauri::command]
+pub fn announce_accessibility(app: AppHandle, message: String) -> Result<(), String> {
+    let message = message.trim();
+    if message.is_empty() || message.chars().count() > MAX_ANNOUNCEMENT_LEN {
+        return Err(format!(
+            "Announcement must be between 1 and {} characters",
+            MAX_ANNOUNCEMENT_LEN
+        ));
+    }
+    announce(&app, message);
+    Ok(())
+}
@@ -0,0 +1,335 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use argon2::password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+
+use crate::store::{self, emit_to_main, now_secs};
+use crate::{accessibility, tray};
+
+const LOCK_FILE: &str = "app_lock.json";
+
+// Passcode length bounds (characters)
+const MIN_PASSCODE_LEN: usize = 4;
+const MAX_PASSCODE_LEN: usize = 128;
+
+// Failed attempts allowed before lockout backoff starts
+const FREE_ATTEMPTS: u32 = 5;
+const BASE_LOCKOUT_SECS: u64 = 30;
+const MAX_LOCKOUT_SECS: u64 = 60 * 60;
+
+// Auto-lock timeout bounds (minutes) and how often the idle timer checks
+const MAX_AUTO_LOCK_MINUTES: u32 = 8 * 60;
+const IDLE_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Persisted lock configuration, stored in the app config directory
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct LockRecord {
+    passcode_hash: Option<String>,
+    failed_attempts: u32,
+    locked_until: Option<u64>,
+    auto_lock_minutes: Option<u32>,
+}
+
+struct LockInner {
+    record: LockRecord,
+    locked: bool,
+    /// Unix seconds. Wall-clock time, because monotonic clocks stop while the
+    /// machine sleeps and a closed laptop must still count as idle.
+    last_activity: u64,
+}
+
+/// Backend-owned app lock. The webview only renders the lock screen;
+/// whether the app is locked is decided here.
+pub struct AppLock {
+    path: PathBuf,
+    inner: Mutex<LockInner>,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockStatus {
+    enabled: bool,
+    locked: bool,
+    retry_after_secs: u64,
+    auto_lock_minutes: Option<u32>,
+}
+
+impl AppLock {
+    /// Loads the lock record from disk. The app starts locked whenever a
+    /// passcode is configured.
+    pub fn load(app: &AppHandle) -> Result<Self, String> {
+        let path = store::config_path(app, LOCK_FILE)?;
+        let record: LockRecord = store::read_json(&path);
+        let locked = record.passcode_hash.is_some();
+
+        Ok(Self {
+            path,
+            inner: Mutex::new(LockInner {
+                record,
+                locked,
+                last_activity: now_secs(),
+            }),
+        })
+    }
+
+    /// Locks the app if a passcode is configured. Returns whether it is now locked.
+    pub fn lock(&self) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        inner.locked = inner.record.passcode_hash.is_some();
+        inner.locked
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.inner.lock().unwrap().locked
+    }
+
+    /// Guard for commands that read or change user data
+    pub fn ensure_unlocked(&self) -> Result<(), String> {
+        if self.is_locked() {
+            return Err("App is locked".to_string());
+        }
+        Ok(())
+    }
+
+    /// Returns whether a passcode is set and the auto-lock timeout
+    pub fn configuration(&self) -> (bool, Option<u32>) {
+        let inner = self.inner.lock().unwrap();
+        (inner.record.passcode_hash.is_some(), inner.record.auto_lock_minutes)
+    }
+
+    /// Forgets the in-memory configuration after the on-disk record was erased
+    pub fn reset(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.record = LockRecord::default();
+        inner.locked = false;
+    }
+
+    /// Locks the app when the configured idle period has elapsed.
+    /// Returns true only on the transition from unlocked to locked.
+    fn lock_if_idle(&self) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        let Some(minutes) = inner.record.auto_lock_minutes else {
+            return false;
+        };
+        if inner.locked || inner.record.passcode_hash.is_none() {
+            return false;
+        }
+        if now_secs().saturating_sub(inner.last_activity) < u64::from(minutes) * 60 {
+            return false;
+        }
+        inner.locked = true;
+        true
+    }
+
+    fn status(inner: &LockInner) -> LockStatus {
+        LockStatus {
+            enabled: inner.record.passcode_hash.is_some(),
+            locked: inner.locked,
+            retry_after_secs: retry_after(&inner.record),
+            auto_lock_minutes: inner.record.auto_lock_minutes,
+        }
+    }
+
+    fn save(&self, record: &LockRecord) -> Result<(), String> {
+        store::write_json(&self.path, record)
+    }
+
+    /// Checks a passcode against the stored hash, applying lockout backoff.
+    fn check_passcode(&self, inner: &mut LockInner, passcode: &str) -> Result<(), String> {
+        let Some(hash) = inner.record.passcode_hash.clone() else {
+            return Ok(());
+        };
+
+        let wait = retry_after(&inner.record);
+        if wait > 0 {
+            return Err(format!("Too many attempts. Try again in {} seconds.", wait));
+        }
+
+        if verify_passcode(passcode, &hash) {
+            inner.record.failed_attempts = 0;
+            inner.record.locked_until = None;
+            self.save(&inner.record)?;
+            return Ok(());
+        }
+
+        inner.record.failed_attempts = inner.record.failed_attempts.saturating_add(1);
+        if inner.record.failed_attempts >= FREE_ATTEMPTS {
+            inner.record.locked_until = Some(now_secs() + lockout_secs(inner.record.failed_attempts));
+        }
+        self.save(&inner.record)?;
+        Err("Incorrect passcode".to_string())
+    }
+}
+
+/// Spawns the idle timer that enforces the auto-lock timeout.
+/// The main window is told to blank itself via the `app-locked` event.
+pub fn spawn_idle_monitor(app: AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(IDLE_POLL_INTERVAL);
+        lock_if_idle(&app);
+    });
+}
+
+/// Locks the app if the idle period has elapsed. Also called on system wake,
+/// so a machine that slept past the timeout is locked before anything is shown.
+pub fn lock_if_idle(app: &AppHandle) {
+    if !app.state::<AppLock>().lock_if_idle() {
+        return;
+    }
+
+    #[cfg(debug_assertions)]
+    println!("Auto-lock triggered after inactivity");
+    emit_to_main(app, "app-locked", ());
+    tray::refresh_badge(app);
+    accessibility::announce(app, "App locked after inactivity");
+}
+
+/// Tells the main window that the lock settings changed, so it can start or
+/// stop reporting activity without reloading the status
+fn notify_changed(app: &AppHandle, status: LockStatus) -> LockStatus {
+    emit_to_main(app, "app-lock-changed", status.clone());
+    status
+}
+
+/// Lockout doubles with each failure past the free attempts, capped at an hour
+fn lockout_secs(failed_attempts: u32) -> u64 {
+    let exponent = failed_attempts.saturating_sub(FREE_ATTEMPTS).min(16);
+    (BASE_LOCKOUT_SECS << exponent).min(MAX_LOCKOUT_SECS)
+}
+
+fn retry_after(record: &LockRecord) -> u64 {
+    record
+        .locked_until
+        .map(|until| until.saturating_sub(now_secs()))
+        .unwrap_or(0)
+}
+
+fn validate_passcode(passcode: &str) -> Result<(), String> {
+    let len = passcode.chars().count();
+    if !(MIN_PASSCODE_LEN..=MAX_PASSCODE_LEN).contains(&len) {
+        return Err(format!(
+            "Passcode must be between {} and {} characters",
+            MIN_PASSCODE_LEN, MAX_PASSCODE_LEN
+        ));
+    }
+    Ok(())
+}
+
+fn hash_passcode(passcode: &str) -> Result<String, String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(passcode.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| e.to_string())
+}
+
+fn verify_passcode(passcode: &str, hash: &str) -> bool {
+    PasswordHash::new(hash)
+        .map(|parsed| Argon2::default().verify_password(passcode.as_bytes(), &parsed).is_ok())
+        .unwrap_or(false)
+}
+
+#[tauri::command]
+pub fn get_app_lock_status(lock: State<'_, AppLock>) -> LockStatus {
+    AppLock::status(&lock.inner.lock().unwrap())
+}
+
+#[tauri::command]
+pub fn unlock_app(app: AppHandle, lock: State<'_, AppLock>, passcode: String) -> Result<LockStatus, String> {
+    let status = {
+        let mut inner = lock.inner.lock().unwrap();
+        lock.check_passcode(&mut inner, &passcode)?;
+        inner.locked = false;
+        inner.last_activity = now_secs();
+        AppLock::status(&inner)
+    };
+    // The badge reads the lock state, so refresh it after releasing the guard
+    tray::refresh_badge(&app);
+    Ok(status)
+}
+
+/// Resets the idle timer. The webview calls this (throttled) on user input.
+#[tauri::command]
+pub fn report_activity(lock: State<'_, AppLock>) {
+    lock.inner.lock().unwrap().last_activity = now_secs();
+}
+
+/// Sets the inactivity period after which the app locks itself; `None` disables auto-lock
+#[tauri::command]
+pub fn set_auto_lock_timeout(
+    app: AppHandle,
+    lock: State<'_, AppLock>,
+    minutes: Option<u32>,
+) -> Result<LockStatus, String> {
+    if let Some(minutes) = minutes {
+        if !(1..=MAX_AUTO_LOCK_MINUTES).contains(&minutes) {
+            return Err(format!(
+                "Auto-lock timeout must be between 1 and {} minutes",
+                MAX_AUTO_LOCK_MINUTES
+            ));
+        }
+    }
+
+    let mut inner = lock.inner.lock().unwrap();
+    if inner.locked {
+        return Err("App is locked".to_string());
+    }
+    inner.record.auto_lock_minutes = minutes;
+    lock.save(&inner.record)?;
+    inner.last_activity = now_secs();
+    Ok(notify_changed(&app, AppLock::status(&inner)))
+}
+
+#[tauri::command]
+pub fn lock_app(app: AppHandle, lock: State<'_, AppLock>) -> LockStatus {
+    lock.lock();
+    tray::refresh_badge(&app);
+    AppLock::status(&lock.inner.lock().unwrap())
+}
+
+/// Sets or changes the passcode. Changing an existing passcode requires the current one.
+#[tauri::command]
+pub fn set_app_passcode(
+    app: AppHandle,
+    lock: State<'_, AppLock>,
+    current: Option<String>,
+    passcode: String,
+) -> Result<LockStatus, String> {
+    validate_passcode(&passcode)?;
+
+    let mut inner = lock.inner.lock().unwrap();
+    if inner.record.passcode_hash.is_some() {
+        lock.check_passcode(&mut inner, current.as_deref().unwrap_or_default())?;
+    }
+
+    let auto_lock_minutes = inner.record.auto_lock_minutes;
+    inner.record = LockRecord {
+        passcode_hash: Some(hash_passcode(&passcode)?),
+        auto_lock_minutes,
+        ..LockRecord::default()
+    };
+    lock.save(&inner.record)?;
+    inner.locked = false;
+    Ok(notify_changed(&app, AppLock::status(&inner)))
+}
+
+#[tauri::command]
+pub fn clear_app_passcode(app: AppHandle, lock: State<'_, AppLock>, current: String) -> Result<LockStatus, String> {
+    let mut inner = lock.inner.lock().unwrap();
+    lock.check_passcode(&mut inner, &current)?;
+
+    let auto_lock_minutes = inner.record.auto_lock_minutes;
+    inner.record = LockRecord {
+        auto_lock_minutes,
+        ..LockRecord::default()
+    };
+    lock.save(&inner.record)?;
+    inner.locked = false;
+    Ok(notify_changed(&app, AppLock::status(&inner)))
+}
@@ -0,0 +1,159 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State, WebviewWindow};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+use crate::app_lock::AppLock;
+use crate::session::SessionGuard;
+use crate::storage::TaskStore;
+use crate::store;
+
+// Overwrite buffer size used when scrubbing file contents
+const SCRUB_CHUNK: usize = 64 * 1024;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EraseEntry {
+    label: &'static str,
+    path: String,
+    existed: bool,
+    removed: bool,
+    error: Option<String>,
+}
+
+/// Final verification report returned to the webview after a wipe
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EraseReport {
+    locations: Vec<EraseEntry>,
+    browsing_data_cleared: bool,
+    verified: bool,
+}
+
+/// Every directory the app writes to. Platforms often map several of these
+/// to the same path, so duplicates are dropped.
+fn app_directories(app: &AppHandle) -> Vec<(&'static str, PathBuf)> {
+    let path = app.path();
+    let candidates = [
+        ("data", path.app_data_dir()),
+        ("local data", path.app_local_data_dir()),
+        ("config", path.app_config_dir()),
+        ("cache", path.app_cache_dir()),
+        ("logs", path.app_log_dir()),
+    ];
+
+    let mut dirs: Vec<(&'static str, PathBuf)> = Vec::new();
+    for (label, dir) in candidates {
+        if let Ok(dir) = dir {
+            if !dirs.iter().any(|(_, existing)| existing == &dir) {
+                dirs.push((label, dir));
+            }
+        }
+    }
+    dirs
+}
+
+/// Overwrites a file with zeros before it is unlinked
+fn scrub_file(path: &Path) -> io::Result<()> {
+    let len = fs::metadata(path)?.len();
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    let zeros = vec![0u8; SCRUB_CHUNK];
+    let mut remaining = len;
+    while remaining > 0 {
+        let n = remaining.min(SCRUB_CHUNK as u64) as usize;
+        file.write_all(&zeros[..n])?;
+        remaining -= n as u64;
+    }
+    file.sync_all()
+}
+
+/// Scrubs every regular file under `dir`, skipping symlinks, then removes the tree
+fn scrub_and_remove(dir: &Path) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            scrub_and_remove(&entry.path())?;
+        } else if file_type.is_file() {
+            scrub_file(&entry.path())?;
+        }
+    }
+    fs::remove_dir_all(dir)
+}
+
+/// Permanently deletes all local app data after a native confirmation dialog.
+///
+/// Returns `None` if the user cancels. The app stores no keychain entries,
+/// so the wipe covers the app directories and the webview's browsing data
+/// (which holds the Supabase session).
+#[tauri::command]
+pub async fn erase_all_data(
+    app: AppHandle,
+    window: WebviewWindow,
+    lock: State<'_, AppLock>,
+    tasks: State<'_, TaskStore>,
+    session: State<'_, SessionGuard>,
+) -> Result<Option<EraseReport>, String> {
+    if lock.is_locked() {
+        return Err("App is locked".to_string());
+    }
+
+    let confirmed = app
+        .dialog()
+        .message("This permanently deletes all local data, settings, caches, and logs, and signs you out. This cannot be undone.")
+        .title("Erase All Data")
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            "Erase".to_string(),
+            "Cancel".to_string(),
+        ))
+        .blocking_show();
+    if !confirmed {
+        return Ok(None);
+    }
+
+    // Stop every subsystem from writing before its files are deleted: settings
+    // writes are refused from here on, and the task database (which also holds
+    // reminders) is released
+    store::block_writes();
+    tasks.close();
+    lock.reset();
+    session.reset();
+
+    let mut locations = Vec::new();
+    for (label, dir) in app_directories(&app) {
+        let existed = dir.exists();
+        let error = if existed {
+            scrub_and_remove(&dir).err().map(|e| e.to_string())
+        } else {
+            None
+        };
+        locations.push(EraseEntry {
+            label,
+            path: dir.display().to_string(),
+            existed,
+            removed: false,
+            error,
+        });
+    }
+
+    let browsing_data_cleared = window.clear_all_browsing_data().is_ok();
+
+    // Verify last, so anything written while the wipe ran is caught
+    for entry in &mut locations {
+        entry.removed = !Path::new(&entry.path).exists();
+    }
+
+    let verified = browsing_data_cleared && locations.iter().all(|entry| entry.removed);
+    #[cfg(debug_assertions)]
+    println!("Erase completed, verified: {}", verified);
+
+    Ok(Some(EraseReport {
+        locations,
+        browsing_data_cleared,
+        verified,
+    }))
+}
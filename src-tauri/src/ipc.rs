@@ -0,0 +1,111 @@
+// Commands each window label may invoke. Windows not listed here can't
+// invoke any app command; plugin commands are still governed by capabilities.
+const WINDOW_COMMANDS: &[(&str, &[&str])] = &[
+    (
+        "main",
+        &[
+            "get_app_lock_status",
+            "unlock_app",
+            "lock_app",
+            "report_activity",
+            "set_auto_lock_timeout",
+            "set_app_passcode",
+            "clear_app_passcode",
+            "erase_all_data",
+            "security_audit",
+            "record_sign_in",
+            "record_sign_out",
+            "check_session",
+            "get_session_policy",
+            "set_session_policy",
+            "get_capture_protection",
+            "set_capture_protection",
+            "get_update_policy",
+            "set_update_policy",
+            "defer_update",
+            "install_update_now",
+            "announce_accessibility",
+            "get_layout_direction",
+            "get_work_schedule",
+            "set_work_schedule",
+            "next_business_day",
+            "add_business_days",
+            "parse_recurrence",
+            "preview_recurrence",
+            "list_tasks",
+            "create_task",
+            "update_task",
+            "delete_task",
+            "toggle_complete",
+            "pending_task_changes",
+            "mark_tasks_synced",
+            "merge_remote_tasks",
+            "clear_local_tasks",
+            "list_reminders",
+            "schedule_reminder",
+            "cancel_reminder",
+            "snooze_reminder",
+        ],
+    ),
+    ("quick-add", &["get_layout_direction", "quick_add_task"]),
+];
+
+/// Validates that a command is in the allowlist for the invoking window.
+/// This extends the menu-ID allowlist to the whole app command surface.
+pub fn is_command_allowed(window_label: &str, command: &str) -> bool {
+    WINDOW_COMMANDS
+        .iter()
+        .find(|(label, _)| *label == window_label)
+        .is_some_and(|(_, commands)| commands.contains(&command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Command names registered in `generate_handler!` in main.rs
+    fn handler_commands() -> Vec<&'static str> {
+        let main = include_str!("main.rs");
+        let start = main.find("generate_handler![").expect("generate_handler! not found");
+        let end = start + main[start..].find("];").expect("unterminated generate_handler!");
+        main[start..end]
+            .lines()
+            .skip(1)
+            .filter_map(|line| line.trim().trim_end_matches(',').rsplit("::").next())
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    #[test]
+    fn allowlisted_commands_are_registered() {
+        let handlers = handler_commands();
+        for (label, commands) in WINDOW_COMMANDS {
+            for command in *commands {
+                assert!(
+                    handlers.contains(command),
+                    "{:?} is allowed for {:?} but not registered",
+                    command,
+                    label
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn registered_commands_are_allowlisted() {
+        for command in handler_commands() {
+            assert!(
+                WINDOW_COMMANDS.iter().any(|(_, commands)| commands.contains(&command)),
+                "{:?} is registered but no window may invoke it",
+                command
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_windows_are_rejected() {
+        assert!(is_command_allowed("main", "list_tasks"));
+        assert!(!is_command_allowed("quick-add", "erase_all_data"));
+        assert!(!is_command_allowed("other", "get_layout_direction"));
+    }
+}
@@ -0,0 +1,57 @@
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use chrono::{Local, NaiveDate};
+use tauri::AppHandle;
+
+use crate::app_lock;
+use crate::store::emit_to_main;
+use crate::tray;
+
+// How often the monitor thread wakes up to compare clocks
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+// Extra wall-clock time beyond the poll interval that counts as a system sleep
+const SLEEP_THRESHOLD: Duration = Duration::from_secs(60);
+
+/// Spawns a background thread that detects system wake and local date changes.
+///
+/// A sleeping machine does not run our thread, so when the wall clock jumps
+/// further than one poll interval we treat it as a wake. Events are only
+/// emitted to the main window, matching the menu event handling.
+pub fn spawn_monitor(app: AppHandle) {
+    thread::spawn(move || {
+        let mut last_tick = SystemTime::now();
+        let mut last_date = Local::now().date_naive();
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+
+            let now = SystemTime::now();
+            let elapsed = now.duration_since(last_tick).unwrap_or_default();
+            last_tick = now;
+
+            if elapsed > POLL_INTERVAL + SLEEP_THRESHOLD {
+                #[cfg(debug_assertions)]
+                println!("System wake detected after {:?}", elapsed);
+                app_lock::lock_if_idle(&app);
+                emit_to_main(&app, "system-resumed", ());
+            }
+
+            let today = Local::now().date_naive();
+            if today != last_date {
+                #[cfg(debug_assertions)]
+                println!("Local date changed: {} -> {}", last_date, today);
+                last_date = today;
+                emit_to_main(&app, "day-changed", format_date(today));
+                tray::refresh_badge(&app);
+            }
+        }
+    });
+}
+
+/// Formats a date as ISO 8601 (YYYY-MM-DD) for event payloads
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
@@ -0,0 +1,42 @@
+use serde::Serialize;
+
+// Primary language subtags written right-to-left
+const RTL_LANGUAGES: &[&str] = &["ar", "ckb", "dv", "fa", "he", "iw", "ps", "sd", "ug", "ur", "yi"];
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LayoutDirection {
+    Ltr,
+    Rtl,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutInfo {
+    locale: String,
+    direction: LayoutDirection,
+}
+
+/// Returns the layout direction for a BCP 47 locale tag such as "ar-EG" or "he_IL"
+pub fn direction_for(locale: &str) -> LayoutDirection {
+    let language = locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    if RTL_LANGUAGES.contains(&language.as_str()) {
+        LayoutDirection::Rtl
+    } else {
+        LayoutDirection::Ltr
+    }
+}
+
+/// Reports the system locale and whether the UI should be mirrored
+#[tauri::command]
+pub fn get_layout_direction() -> LayoutInfo {
+    let locale = sys_locale::get_locale().unwrap_or_else(|| "en-US".to_string());
+    LayoutInfo {
+        direction: direction_for(&locale),
+        locale,
+    }
+}
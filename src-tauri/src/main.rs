@@ -1,190 +1,134 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use tauri::{
-    Manager, 
-    Emitter,
-    menu::{MenuBuilder, MenuItemBuilder, PredefinedMenuItem, SubmenuBuilder, AboutMetadata},
-};
+use tauri::Manager;
 
-// Allowed menu event IDs for input validation
-const ALLOWED_MENU_IDS: &[&str] = &["preferences", "sign_out"];
-
-/// Validates that a menu event ID is in the allowlist
-/// This prevents processing of unexpected or malicious menu IDs
-fn is_valid_menu_id(id: &str) -> bool {
-    ALLOWED_MENU_IDS.contains(&id)
-}
+mod accessibility;
+mod app_lock;
+mod erase;
+mod ipc;
+mod lifecycle;
+mod locale;
+mod platform;
+mod privacy;
+mod quick_add;
+mod recurrence;
+mod reminders;
+mod security_audit;
+mod session;
+mod storage;
+mod store;
+mod tray;
+mod updates;
+mod work_hours;
 
 fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_process::init())
+        .plugin(tauri_plugin_notification::init())
         .setup(|app| {
             #[cfg(desktop)]
-            app.handle().plugin(tauri_plugin_updater::Builder::new().build())?;
-            let window = app.get_webview_window("main").unwrap();
-            
-            #[cfg(target_os = "macos")]
             {
-                use window_vibrancy::{apply_vibrancy, NSVisualEffectMaterial};
-                
-                #[cfg(debug_assertions)]
-                println!("===== MENU SETUP STARTING =====");
-                
-                // Apply native macOS vibrancy for semi-transparent blur effect
-                apply_vibrancy(
-                    &window,
-                    NSVisualEffectMaterial::HudWindow,
-                    None,
-                    Some(12.0) // Corner radius
-                )
-                .expect("Unsupported platform! 'apply_vibrancy' is only supported on macOS");
-
-                // Create custom menu items - renamed to "Preferences"
-                let preferences = MenuItemBuilder::with_id("preferences", "Preferences...")
-                    .accelerator("Cmd+,")
-                    .build(app)?;
-                #[cfg(debug_assertions)]
-                println!("Created preferences menu item");
-                
-                let sign_out = MenuItemBuilder::with_id("sign_out", "Sign Out")
-                    .build(app)?;
-                #[cfg(debug_assertions)]
-                println!("Created sign out menu item");
-
-                // Build the App submenu with custom about text
-                let about_metadata = AboutMetadata {
-                    name: Some("Todo App".to_string()),
-                    version: Some("1.0.0".to_string()),
-                    short_version: Some("1.0".to_string()),
-                    authors: Some(vec!["codebyfourn".to_string()]),
-                    comments: Some("No B.S. todo app and this is all you need to manage daily tasks.\n\nCompletely free and no, I will not sell your data.\n\nThis is just a project I made to hopefully be hired somewhere :)\n\nContact: lukefournierdev@gmail.com".to_string()),
-                    copyright: Some("Copyright © 2025 codebyfourn. All rights reserved.".to_string()),
-                    website: Some("https://github.com/lilfourn".to_string()),
-                    website_label: Some("View GitHub Profile".to_string()),
-                    icon: None,
-                    ..Default::default()
-                };
-                
-                let app_menu = SubmenuBuilder::new(app, "Todo App")
-                    .item(&PredefinedMenuItem::about(
-                        app, 
-                        Some("About Todo App"),
-                        Some(about_metadata)
-                    )?)
-                    .separator()
-                    .item(&preferences)
-                    .separator()
-                    .item(&sign_out)
-                    .separator()
-                    .item(&PredefinedMenuItem::services(app, None)?)
-                    .separator()
-                    .item(&PredefinedMenuItem::hide(app, None)?)
-                    .item(&PredefinedMenuItem::hide_others(app, None)?)
-                    .item(&PredefinedMenuItem::show_all(app, None)?)
-                    .separator()
-                    .item(&PredefinedMenuItem::quit(app, None)?)
-                    .build()?;
-                #[cfg(debug_assertions)]
-                println!("Built app menu");
-
-                // Add other menus (File, Edit, etc.)
-                let file_menu = SubmenuBuilder::new(app, "File")
-                    .item(&PredefinedMenuItem::close_window(app, None)?)
-                    .build()?;
-                #[cfg(debug_assertions)]
-                println!("Built file menu");
-
-                let edit_menu = SubmenuBuilder::new(app, "Edit")
-                    .item(&PredefinedMenuItem::undo(app, None)?)
-                    .item(&PredefinedMenuItem::redo(app, None)?)
-                    .separator()
-                    .item(&PredefinedMenuItem::cut(app, None)?)
-                    .item(&PredefinedMenuItem::copy(app, None)?)
-                    .item(&PredefinedMenuItem::paste(app, None)?)
-                    .item(&PredefinedMenuItem::select_all(app, None)?)
-                    .build()?;
-                #[cfg(debug_assertions)]
-                println!("Built edit menu");
-
-                let window_menu = SubmenuBuilder::new(app, "Window")
-                    .item(&PredefinedMenuItem::minimize(app, None)?)
-                    .item(&PredefinedMenuItem::maximize(app, None)?)
-                    .build()?;
-                #[cfg(debug_assertions)]
-                println!("Built window menu");
-
-                // Build the complete menu bar
-                let menu = MenuBuilder::new(app)
-                    .item(&app_menu)
-                    .item(&file_menu)
-                    .item(&edit_menu)
-                    .item(&window_menu)
-                    .build()?;
-                #[cfg(debug_assertions)]
-                println!("Built complete menu");
-
-                app.set_menu(menu)?;
-                #[cfg(debug_assertions)]
-                println!("===== MENU SET SUCCESSFULLY =====");
+                app.handle().plugin(tauri_plugin_updater::Builder::new().build())?;
+                app.manage(updates::UpdateManager::load(app.handle())?);
+                updates::spawn_checker(app.handle().clone());
 
-                // Handle menu events with input validation
-                app.on_menu_event(move |app, event| {
-                    #[cfg(debug_assertions)]
-                    println!("Menu event received: {:?}", event.id());
-                    
-                    // Layer 1: Validate event ID against allowlist
-                    let event_id = event.id().as_ref();
-                    if !is_valid_menu_id(event_id) {
-                        #[cfg(debug_assertions)]
-                        println!("Invalid menu ID rejected: {:?}", event_id);
-                        return;
-                    }
-                    
-                    match event_id {
-                        "preferences" => {
-                            #[cfg(debug_assertions)]
-                            println!("Preferences clicked!");
-                            // Emit event to navigate to preferences
-                            if let Some(window) = app.get_webview_window("main") {
-                                // Validate window label before emitting
-                                if window.label() != "main" {
-                                    #[cfg(debug_assertions)]
-                                    println!("Event rejected: window label is not 'main'");
-                                    return;
-                                }
-                                window.emit("navigate-to-preferences", ()).unwrap_or_else(|_e| {
-                                    #[cfg(debug_assertions)]
-                                    eprintln!("Failed to emit navigate-to-preferences event: {:?}", _e);
-                                });
-                            }
-                        }
-                        "sign_out" => {
-                            #[cfg(debug_assertions)]
-                            println!("Sign out clicked!");
-                            // Emit event to sign out user
-                            if let Some(window) = app.get_webview_window("main") {
-                                // Validate window label before emitting
-                                if window.label() != "main" {
-                                    #[cfg(debug_assertions)]
-                                    println!("Event rejected: window label is not 'main'");
-                                    return;
-                                }
-                                window.emit("sign-out-user", ()).unwrap_or_else(|_e| {
-                                    #[cfg(debug_assertions)]
-                                    eprintln!("Failed to emit sign-out-user event: {:?}", _e);
-                                });
-                            }
+                // Cmd/Ctrl+Shift+T opens the quick-capture window from anywhere.
+                // Another app may already own the shortcut; the tray still works then.
+                use tauri_plugin_global_shortcut::ShortcutState;
+                let shortcut_plugin = tauri_plugin_global_shortcut::Builder::new()
+                    .with_shortcuts([quick_add::SHORTCUT])?
+                    .with_handler(|app, _shortcut, event| {
+                        if event.state() == ShortcutState::Pressed {
+                            quick_add::show(app);
                         }
-                        _ => {}
-                    }
-                });
+                    })
+                    .build();
+                if let Err(_e) = app.handle().plugin(shortcut_plugin) {
+                    #[cfg(debug_assertions)]
+                    eprintln!("Failed to register quick-add shortcut: {:?}", _e);
+                }
             }
+            app.manage(app_lock::AppLock::load(app.handle())?);
+            app.manage(session::SessionGuard::load(app.handle())?);
+            app.manage(storage::TaskStore::open(app.handle())?);
+            tray::setup(app.handle())?;
+            app_lock::spawn_idle_monitor(app.handle().clone());
+            lifecycle::spawn_monitor(app.handle().clone());
+            reminders::spawn_scheduler(app.handle().clone());
+            let window = app.get_webview_window("main").unwrap();
+            privacy::apply_saved(app.handle(), &window)?;
+            
+            platform::setup(app, &window)?;
             
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .invoke_handler({
+            let handler = tauri::generate_handler![
+                app_lock::get_app_lock_status,
+                app_lock::unlock_app,
+                app_lock::lock_app,
+                app_lock::report_activity,
+                app_lock::set_auto_lock_timeout,
+                app_lock::set_app_passcode,
+                app_lock::clear_app_passcode,
+                erase::erase_all_data,
+                security_audit::security_audit,
+                session::record_sign_in,
+                session::record_sign_out,
+                session::check_session,
+                session::get_session_policy,
+                session::set_session_policy,
+                privacy::get_capture_protection,
+                privacy::set_capture_protection,
+                updates::get_update_policy,
+                updates::set_update_policy,
+                updates::defer_update,
+                updates::install_update_now,
+                accessibility::announce_accessibility,
+                locale::get_layout_direction,
+                work_hours::get_work_schedule,
+                work_hours::set_work_schedule,
+                work_hours::next_business_day,
+                work_hours::add_business_days,
+                recurrence::parse_recurrence,
+                recurrence::preview_recurrence,
+                storage::list_tasks,
+                storage::create_task,
+                storage::update_task,
+                storage::delete_task,
+                storage::toggle_complete,
+                storage::pending_task_changes,
+                storage::mark_tasks_synced,
+                storage::merge_remote_tasks,
+                storage::clear_local_tasks,
+                reminders::list_reminders,
+                reminders::schedule_reminder,
+                reminders::cancel_reminder,
+                reminders::snooze_reminder,
+                quick_add::quick_add_task,
+            ];
+            move |invoke: tauri::ipc::Invoke| {
+                // Layer 1: Validate the command against the invoking window's allowlist
+                let webview = invoke.message.webview();
+                let command = invoke.message.command();
+                if !ipc::is_command_allowed(webview.label(), command) {
+                    #[cfg(debug_assertions)]
+                    println!("Command {:?} rejected for window {:?}", command, webview.label());
+                    invoke.resolver.reject("Command not allowed for this window");
+                    return true;
+                }
+                handler(invoke)
+            }
+        })
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            // Apply a downloaded update as the app quits ("install on quit")
+            #[cfg(desktop)]
+            if let tauri::RunEvent::Exit = event {
+                updates::install_on_exit(_app);
+            }
+        });
 }
\ No newline at end of file
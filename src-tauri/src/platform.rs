@@ -0,0 +1,268 @@
+#[cfg(not(target_os = "macos"))]
+use tauri::menu::Menu;
+use tauri::menu::{AboutMetadata, MenuBuilder, MenuEvent, MenuItemBuilder, PredefinedMenuItem, SubmenuBuilder};
+use tauri::{App, AppHandle, Manager, WebviewWindow};
+
+use crate::quick_add;
+use crate::store::emit_to_main;
+
+// Allowed menu event IDs for input validation (app menu and tray menu)
+const ALLOWED_MENU_IDS: &[&str] = &["preferences", "sign_out", "quick_add", "show_app", "quit"];
+
+// Tint for the Acrylic fallback on Windows 10 (RGBA)
+#[cfg(target_os = "windows")]
+const ACRYLIC_TINT: (u8, u8, u8, u8) = (30, 30, 30, 160);
+
+// Solid background used where no translucent window effect is available
+#[cfg(not(target_os = "macos"))]
+const OPAQUE_BACKGROUND: tauri::window::Color = tauri::window::Color(30, 30, 30, 255);
+
+/// Validates that a menu event ID is in the allowlist
+/// This prevents processing of unexpected or malicious menu IDs
+fn is_valid_menu_id(id: &str) -> bool {
+    ALLOWED_MENU_IDS.contains(&id)
+}
+
+/// Applies the platform's window effect and menu. Every platform emits the
+/// same `navigate-to-preferences` / `sign-out-user` events, so the frontend
+/// doesn't need to know which one it runs on.
+pub fn setup(app: &App, window: &WebviewWindow) -> tauri::Result<()> {
+    #[cfg(target_os = "macos")]
+    setup_macos(app, window)?;
+    #[cfg(target_os = "windows")]
+    setup_windows(app, window)?;
+    #[cfg(target_os = "linux")]
+    setup_linux(app, window)?;
+
+    // Also receives tray menu events
+    app.on_menu_event(handle_menu_event);
+    Ok(())
+}
+
+fn about_metadata() -> AboutMetadata<'static> {
+    AboutMetadata {
+        name: Some("Todo App".to_string()),
+        version: Some("1.0.0".to_string()),
+        short_version: Some("1.0".to_string()),
+        authors: Some(vec!["codebyfourn".to_string()]),
+        comments: Some("No B.S. todo app and this is all you need to manage daily tasks.\n\nCompletely free and no, I will not sell your data.\n\nThis is just a project I made to hopefully be hired somewhere :)\n\nContact: lukefournierdev@gmail.com".to_string()),
+        copyright: Some("Copyright © 2025 codebyfourn. All rights reserved.".to_string()),
+        website: Some("https://github.com/lilfourn".to_string()),
+        website_label: Some("View GitHub Profile".to_string()),
+        icon: None,
+        ..Default::default()
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn setup_macos(app: &App, window: &WebviewWindow) -> tauri::Result<()> {
+    use window_vibrancy::{apply_vibrancy, NSVisualEffectMaterial};
+
+    #[cfg(debug_assertions)]
+    println!("===== MENU SETUP STARTING =====");
+
+    // Apply native macOS vibrancy for semi-transparent blur effect
+    apply_vibrancy(
+        window,
+        NSVisualEffectMaterial::HudWindow,
+        None,
+        Some(12.0) // Corner radius
+    )
+    .expect("Unsupported platform! 'apply_vibrancy' is only supported on macOS");
+
+    // Create custom menu items - renamed to "Preferences"
+    let preferences = MenuItemBuilder::with_id("preferences", "Preferences...")
+        .accelerator("Cmd+,")
+        .build(app)?;
+    #[cfg(debug_assertions)]
+    println!("Created preferences menu item");
+
+    let sign_out = MenuItemBuilder::with_id("sign_out", "Sign Out")
+        .build(app)?;
+    #[cfg(debug_assertions)]
+    println!("Created sign out menu item");
+
+    // Build the App submenu with custom about text
+    let app_menu = SubmenuBuilder::new(app, "Todo App")
+        .item(&PredefinedMenuItem::about(
+            app,
+            Some("About Todo App"),
+            Some(about_metadata())
+        )?)
+        .separator()
+        .item(&preferences)
+        .separator()
+        .item(&sign_out)
+        .separator()
+        .item(&PredefinedMenuItem::services(app, None)?)
+        .separator()
+        .item(&PredefinedMenuItem::hide(app, None)?)
+        .item(&PredefinedMenuItem::hide_others(app, None)?)
+        .item(&PredefinedMenuItem::show_all(app, None)?)
+        .separator()
+        .item(&PredefinedMenuItem::quit(app, None)?)
+        .build()?;
+    #[cfg(debug_assertions)]
+    println!("Built app menu");
+
+    // Add other menus (File, Edit, etc.)
+    let file_menu = SubmenuBuilder::new(app, "File")
+        .item(&PredefinedMenuItem::close_window(app, None)?)
+        .build()?;
+    #[cfg(debug_assertions)]
+    println!("Built file menu");
+
+    let edit_menu = SubmenuBuilder::new(app, "Edit")
+        .item(&PredefinedMenuItem::undo(app, None)?)
+        .item(&PredefinedMenuItem::redo(app, None)?)
+        .separator()
+        .item(&PredefinedMenuItem::cut(app, None)?)
+        .item(&PredefinedMenuItem::copy(app, None)?)
+        .item(&PredefinedMenuItem::paste(app, None)?)
+        .item(&PredefinedMenuItem::select_all(app, None)?)
+        .build()?;
+    #[cfg(debug_assertions)]
+    println!("Built edit menu");
+
+    let window_menu = SubmenuBuilder::new(app, "Window")
+        .item(&PredefinedMenuItem::minimize(app, None)?)
+        .item(&PredefinedMenuItem::maximize(app, None)?)
+        .build()?;
+    #[cfg(debug_assertions)]
+    println!("Built window menu");
+
+    // Build the complete menu bar
+    let menu = MenuBuilder::new(app)
+        .item(&app_menu)
+        .item(&file_menu)
+        .item(&edit_menu)
+        .item(&window_menu)
+        .build()?;
+    #[cfg(debug_assertions)]
+    println!("Built complete menu");
+
+    app.set_menu(menu)?;
+    #[cfg(debug_assertions)]
+    println!("===== MENU SET SUCCESSFULLY =====");
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn setup_windows(app: &App, window: &WebviewWindow) -> tauri::Result<()> {
+    use window_vibrancy::{apply_acrylic, apply_mica};
+
+    // Mica needs Windows 11 and Acrylic Windows 10; older systems get a solid background
+    if apply_mica(window, None).is_err() && apply_acrylic(window, Some(ACRYLIC_TINT)).is_err() {
+        #[cfg(debug_assertions)]
+        println!("No window effect available, using an opaque background");
+        apply_opaque_background(window);
+    }
+
+    window.set_menu(build_window_menu(app)?)?;
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn setup_linux(app: &App, window: &WebviewWindow) -> tauri::Result<()> {
+    // Translucency depends on the compositor, so Linux always gets a solid background
+    apply_opaque_background(window);
+
+    window.set_menu(build_window_menu(app)?)?;
+    Ok(())
+}
+
+#[cfg(not(target_os = "macos"))]
+fn apply_opaque_background(window: &WebviewWindow) {
+    window.set_background_color(Some(OPAQUE_BACKGROUND)).unwrap_or_else(|_e| {
+        #[cfg(debug_assertions)]
+        eprintln!("Failed to set window background: {:?}", _e);
+    });
+}
+
+/// Windows/Linux menu bar with the same actions as the macOS app menu.
+/// It is attached to the main window only, so the quick-add window stays bare.
+#[cfg(not(target_os = "macos"))]
+fn build_window_menu(app: &App) -> tauri::Result<Menu<tauri::Wry>> {
+    let preferences = MenuItemBuilder::with_id("preferences", "Preferences...")
+        .accelerator("Ctrl+,")
+        .build(app)?;
+    let sign_out = MenuItemBuilder::with_id("sign_out", "Sign Out").build(app)?;
+    // The predefined quit item isn't supported on Linux
+    let quit = MenuItemBuilder::with_id("quit", "Quit")
+        .accelerator("Ctrl+Q")
+        .build(app)?;
+
+    let file_menu = SubmenuBuilder::new(app, "File")
+        .item(&preferences)
+        .separator()
+        .item(&sign_out)
+        .separator()
+        .item(&quit)
+        .build()?;
+
+    let edit_menu = SubmenuBuilder::new(app, "Edit")
+        .item(&PredefinedMenuItem::cut(app, None)?)
+        .item(&PredefinedMenuItem::copy(app, None)?)
+        .item(&PredefinedMenuItem::paste(app, None)?)
+        .item(&PredefinedMenuItem::select_all(app, None)?)
+        .build()?;
+
+    let help_menu = SubmenuBuilder::new(app, "Help")
+        .item(&PredefinedMenuItem::about(
+            app,
+            Some("About Todo App"),
+            Some(about_metadata()),
+        )?)
+        .build()?;
+
+    MenuBuilder::new(app)
+        .item(&file_menu)
+        .item(&edit_menu)
+        .item(&help_menu)
+        .build()
+}
+
+fn show_main(app: &AppHandle) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    let result = window
+        .unminimize()
+        .and_then(|_| window.show())
+        .and_then(|_| window.set_focus());
+    if let Err(_e) = result {
+        #[cfg(debug_assertions)]
+        eprintln!("Failed to show main window: {:?}", _e);
+    }
+}
+
+/// Handles menu events from the app menu and the tray, with input validation
+fn handle_menu_event(app: &AppHandle, event: MenuEvent) {
+    #[cfg(debug_assertions)]
+    println!("Menu event received: {:?}", event.id());
+
+    // Layer 1: Validate event ID against allowlist
+    let event_id = event.id().as_ref();
+    if !is_valid_menu_id(event_id) {
+        #[cfg(debug_assertions)]
+        println!("Invalid menu ID rejected: {:?}", event_id);
+        return;
+    }
+
+    match event_id {
+        "preferences" => {
+            #[cfg(debug_assertions)]
+            println!("Preferences clicked!");
+            emit_to_main(app, "navigate-to-preferences", ());
+        }
+        "sign_out" => {
+            #[cfg(debug_assertions)]
+            println!("Sign out clicked!");
+            emit_to_main(app, "sign-out-user", ());
+        }
+        "quick_add" => quick_add::show(app),
+        "show_app" => show_main(app),
+        "quit" => app.exit(0),
+        _ => {}
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State, WebviewWindow};
+
+use crate::app_lock::AppLock;
+use crate::store;
+
+const PRIVACY_FILE: &str = "privacy.json";
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct PrivacySettings {
+    capture_protection: bool,
+}
+
+/// Re-applies the saved screen-capture exclusion to a window when it is created
+pub fn apply_saved(app: &AppHandle, window: &WebviewWindow) -> Result<(), String> {
+    let settings: PrivacySettings = store::read_json(&store::config_path(app, PRIVACY_FILE)?);
+    if settings.capture_protection {
+        window.set_content_protected(true).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_capture_protection(app: AppHandle) -> Result<bool, String> {
+    let settings: PrivacySettings = store::read_json(&store::config_path(&app, PRIVACY_FILE)?);
+    Ok(settings.capture_protection)
+}
+
+/// Excludes every open window from screenshots and screen sharing
+/// (NSWindow sharingType on macOS, SetWindowDisplayAffinity on Windows).
+/// Windows opened later pick the setting up through `apply_saved`.
+#[tauri::command]
+pub fn set_capture_protection(app: AppHandle, lock: State<'_, AppLock>, enabled: bool) -> Result<bool, String> {
+    lock.ensure_unlocked()?;
+    for window in app.webview_windows().values() {
+        window.set_content_protected(enabled).map_err(|e| e.to_string())?;
+    }
+
+    let path = store::config_path(&app, PRIVACY_FILE)?;
+    let mut settings: PrivacySettings = store::read_json(&path);
+    settings.capture_protection = enabled;
+    store::write_json(&path, &settings)?;
+    Ok(enabled)
+}
@@ -0,0 +1,96 @@
+use chrono::Local;
+use tauri::{AppHandle, Manager, State, WebviewUrl, WebviewWindow, WebviewWindowBuilder, WindowEvent};
+
+use crate::app_lock::AppLock;
+use crate::privacy;
+use crate::storage::{TaskInput, TaskStore};
+use crate::store::emit_to_main;
+use crate::tray;
+
+const WINDOW_LABEL: &str = "quick-add";
+
+// Global shortcut that opens the quick-capture window
+pub const SHORTCUT: &str = "CommandOrControl+Shift+T";
+
+// Quick-capture window size (logical pixels)
+const WIDTH: f64 = 520.0;
+const HEIGHT: f64 = 120.0;
+
+/// Shows the always-on-top quick-capture window, creating it on first use.
+/// It hides itself again when it loses focus.
+pub fn show(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window(WINDOW_LABEL) {
+        focus(&window);
+        return;
+    }
+
+    let window = match WebviewWindowBuilder::new(app, WINDOW_LABEL, WebviewUrl::App("index.html".into()))
+        .title("Quick Add Task")
+        .inner_size(WIDTH, HEIGHT)
+        .resizable(false)
+        .minimizable(false)
+        .maximizable(false)
+        .decorations(false)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .center()
+        .focused(true)
+        .build()
+    {
+        Ok(window) => window,
+        Err(_e) => {
+            #[cfg(debug_assertions)]
+            eprintln!("Failed to create quick-add window: {:?}", _e);
+            return;
+        }
+    };
+
+    if let Err(_e) = privacy::apply_saved(app, &window) {
+        #[cfg(debug_assertions)]
+        eprintln!("Failed to apply privacy settings to quick-add window: {}", _e);
+    }
+
+    let handle = app.clone();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Focused(false) = event {
+            if let Some(window) = handle.get_webview_window(WINDOW_LABEL) {
+                window.hide().unwrap_or_else(|_e| {
+                    #[cfg(debug_assertions)]
+                    eprintln!("Failed to hide quick-add window: {:?}", _e);
+                });
+            }
+        }
+    });
+}
+
+fn focus(window: &WebviewWindow) {
+    let result = window.center().and_then(|_| window.show()).and_then(|_| window.set_focus());
+    if let Err(_e) = result {
+        #[cfg(debug_assertions)]
+        eprintln!("Failed to show quick-add window: {:?}", _e);
+    }
+}
+
+/// Saves text captured in the quick-add window as a task due today, then hides the window.
+/// The main window reloads its list on `tasks-changed`.
+#[tauri::command]
+pub fn quick_add_task(
+    app: AppHandle,
+    window: WebviewWindow,
+    store: State<'_, TaskStore>,
+    lock: State<'_, AppLock>,
+    title: String,
+) -> Result<(), String> {
+    lock.ensure_unlocked()?;
+    // Tasks belong to the signed-in account; without one there is nowhere to sync them
+    if store.owner()?.is_none() {
+        return Err("Sign in to add tasks".to_string());
+    }
+
+    let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+    store.create(TaskInput::new(title, Some(today)))?;
+    tray::refresh_badge(&app);
+    emit_to_main(&app, "tasks-changed", ());
+
+    window.hide().map_err(|e| e.to_string())
+}
@@ -0,0 +1,605 @@
+use chrono::{Datelike, Days, Local, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+// Bounds for parsed phrases
+const MAX_PHRASE_LEN: usize = 200;
+const MAX_INTERVAL: u32 = 999;
+
+// Number of occurrences returned by the preview command
+const PREVIEW_COUNT: usize = 5;
+
+// Give up after this many periods so sparse rules (e.g. Feb 29) can't spin forever
+const MAX_PERIODS: u32 = 500;
+
+// Words that carry no meaning once the phrase is tokenized
+const FILLER_WORDS: &[&str] = &["every", "each", "on", "the", "and"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    fn rrule_name(self) -> &'static str {
+        match self {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        }
+    }
+}
+
+/// A BYDAY entry: ISO weekday (1 = Monday) with an optional ordinal
+/// (2 = second, -1 = last) for monthly rules
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeekdaySpec {
+    weekday: u8,
+    ordinal: Option<i8>,
+}
+
+/// Internal recurrence representation, a subset of RFC 5545 RRULE
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecurrenceRule {
+    freq: Frequency,
+    interval: u32,
+    by_day: Vec<WeekdaySpec>,
+    /// Days of the month; -1 is the last day
+    by_month_day: Vec<i8>,
+    /// Picks the nth matching day in the period (BYSETPOS); -1 is the last
+    by_set_pos: Option<i8>,
+    /// The next occurrence is counted from when the task was completed
+    /// rather than from the schedule (not expressible in RRULE)
+    after_completion: bool,
+}
+
+impl RecurrenceRule {
+    fn new(freq: Frequency) -> Self {
+        Self {
+            freq,
+            interval: 1,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_set_pos: None,
+            after_completion: false,
+        }
+    }
+
+    /// Serializes the rule as an RRULE value, e.g. `FREQ=MONTHLY;BYDAY=2TU`
+    pub fn to_rrule(&self) -> String {
+        let mut parts = vec![format!("FREQ={}", self.freq.rrule_name())];
+        if self.interval > 1 {
+            parts.push(format!("INTERVAL={}", self.interval));
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<String> = self
+                .by_day
+                .iter()
+                .map(|spec| {
+                    let code = weekday_code(spec.weekday);
+                    match spec.ordinal {
+                        Some(ordinal) => format!("{}{}", ordinal, code),
+                        None => code.to_string(),
+                    }
+                })
+                .collect();
+            parts.push(format!("BYDAY={}", days.join(",")));
+        }
+        if !self.by_month_day.is_empty() {
+            let days: Vec<String> = self.by_month_day.iter().map(|day| day.to_string()).collect();
+            parts.push(format!("BYMONTHDAY={}", days.join(",")));
+        }
+        if let Some(pos) = self.by_set_pos {
+            parts.push(format!("BYSETPOS={}", pos));
+        }
+        parts.join(";")
+    }
+
+    /// Returns up to `count` occurrences strictly after `start`
+    pub fn occurrences_after(&self, start: NaiveDate, count: usize) -> Vec<NaiveDate> {
+        let mut found = Vec::new();
+        for period in 0..MAX_PERIODS {
+            let Some(step) = period.checked_mul(self.interval) else {
+                break;
+            };
+            let Some(candidates) = self.candidates_in_period(start, step) else {
+                break;
+            };
+            for date in candidates {
+                if date > start {
+                    found.push(date);
+                    if found.len() == count {
+                        return found;
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    /// All matching dates in the period `step` units after the one containing `start`,
+    /// in ascending order. Returns `None` once dates run out of range.
+    fn candidates_in_period(&self, start: NaiveDate, step: u32) -> Option<Vec<NaiveDate>> {
+        match self.freq {
+            Frequency::Daily => start.checked_add_days(Days::new(u64::from(step))).map(|d| vec![d]),
+            Frequency::Weekly => {
+                let offset = u64::from(step) * 7;
+                if self.by_day.is_empty() {
+                    return start.checked_add_days(Days::new(offset)).map(|d| vec![d]);
+                }
+                let monday = start
+                    .checked_sub_days(Days::new(u64::from(start.weekday().num_days_from_monday())))?
+                    .checked_add_days(Days::new(offset))?;
+                let mut days: Vec<NaiveDate> = self
+                    .by_day
+                    .iter()
+                    .filter_map(|spec| monday.checked_add_days(Days::new(u64::from(spec.weekday - 1))))
+                    .collect();
+                days.sort();
+                Some(days)
+            }
+            Frequency::Monthly => {
+                let first = start.with_day(1)?.checked_add_months(Months::new(step))?;
+                Some(self.monthly_candidates(first, start.day()))
+            }
+            Frequency::Yearly => {
+                let year = start.year().checked_add(i32::try_from(step).ok()?)?;
+                if year > 9999 {
+                    return None;
+                }
+                // Feb 29 only exists in leap years; other years are skipped
+                Some(NaiveDate::from_ymd_opt(year, start.month(), start.day()).into_iter().collect())
+            }
+        }
+    }
+
+    fn monthly_candidates(&self, first: NaiveDate, anchor_day: u32) -> Vec<NaiveDate> {
+        let last_day = days_in_month(first);
+        let mut days: Vec<NaiveDate> = Vec::new();
+
+        for &day in &self.by_month_day {
+            let day = if day < 0 { last_day } else { day as u32 };
+            days.extend(first.with_day(day));
+        }
+
+        if let Some(pos) = self.by_set_pos {
+            // e.g. "last weekday of the month": every matching day, then pick one
+            let matching: Vec<NaiveDate> = (1..=last_day)
+                .filter_map(|day| first.with_day(day))
+                .filter(|date| self.by_day.iter().any(|spec| spec.weekday == iso_weekday(*date)))
+                .collect();
+            let index = if pos < 0 {
+                matching.len().checked_sub(pos.unsigned_abs() as usize)
+            } else {
+                (pos as usize).checked_sub(1)
+            };
+            days.extend(index.and_then(|i| matching.get(i).copied()));
+        } else {
+            for spec in &self.by_day {
+                days.extend(nth_weekday(first, last_day, spec.weekday, spec.ordinal.unwrap_or(1)));
+            }
+        }
+
+        if self.by_day.is_empty() && self.by_month_day.is_empty() {
+            // Plain "monthly" repeats on the start date's day; short months are skipped
+            days.extend(first.with_day(anchor_day));
+        }
+
+        days.sort();
+        days.dedup();
+        days
+    }
+}
+
+fn iso_weekday(date: NaiveDate) -> u8 {
+    date.weekday().number_from_monday() as u8
+}
+
+fn days_in_month(first: NaiveDate) -> u32 {
+    first
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .map(|last| last.day())
+        .unwrap_or(28)
+}
+
+/// The nth (or last, for negative n) given weekday of the month starting at `first`
+fn nth_weekday(first: NaiveDate, last_day: u32, weekday: u8, n: i8) -> Option<NaiveDate> {
+    if n > 0 {
+        let offset = (u32::from(weekday) + 7 - u32::from(iso_weekday(first))) % 7;
+        let day = 1 + offset + (n as u32 - 1) * 7;
+        (day <= last_day).then(|| first.with_day(day)).flatten()
+    } else {
+        let last = first.with_day(last_day)?;
+        let offset = (u32::from(iso_weekday(last)) + 7 - u32::from(weekday)) % 7;
+        last_day.checked_sub(offset).and_then(|day| first.with_day(day))
+    }
+}
+
+fn weekday_code(weekday: u8) -> &'static str {
+    match weekday {
+        1 => "MO",
+        2 => "TU",
+        3 => "WE",
+        4 => "TH",
+        5 => "FR",
+        6 => "SA",
+        _ => "SU",
+    }
+}
+
+fn parse_weekday(word: &str) -> Option<u8> {
+    match word.trim_end_matches('s') {
+        "mon" | "monday" => Some(1),
+        "tue" | "tuesday" => Some(2),
+        "wed" | "wedne" | "wednesday" => Some(3),
+        "thu" | "thur" | "thursday" => Some(4),
+        "fri" | "friday" => Some(5),
+        "sat" | "saturday" => Some(6),
+        "sun" | "sunday" => Some(7),
+        _ => None,
+    }
+}
+
+fn parse_count(word: &str) -> Option<u32> {
+    let count = match word {
+        "other" => 2,
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        "ten" => 10,
+        "eleven" => 11,
+        "twelve" => 12,
+        _ => word.parse().ok()?,
+    };
+    (1..=MAX_INTERVAL).contains(&count).then_some(count)
+}
+
+/// Parses "2nd", "second", "last", "15th", ... to a day/position index
+fn parse_ordinal(word: &str) -> Option<i8> {
+    let ordinal = match word {
+        "first" => 1,
+        "second" => 2,
+        "third" => 3,
+        "fourth" => 4,
+        "fifth" => 5,
+        "last" => -1,
+        _ => {
+            let digits = ["st", "nd", "rd", "th"]
+                .iter()
+                .find_map(|suffix| word.strip_suffix(suffix))?;
+            digits.parse().ok()?
+        }
+    };
+    (ordinal == -1 || (1..=31).contains(&ordinal)).then_some(ordinal)
+}
+
+fn parse_unit(word: &str) -> Option<Frequency> {
+    match word {
+        "day" | "days" | "daily" => Some(Frequency::Daily),
+        "week" | "weeks" | "weekly" => Some(Frequency::Weekly),
+        "month" | "months" | "monthly" => Some(Frequency::Monthly),
+        "year" | "years" | "yearly" | "annually" => Some(Frequency::Yearly),
+        _ => None,
+    }
+}
+
+fn weekdays(days: &[u8]) -> Vec<WeekdaySpec> {
+    days.iter()
+        .map(|&weekday| WeekdaySpec { weekday, ordinal: None })
+        .collect()
+}
+
+/// Parses the part of the phrase after the count/unit ("monday friday",
+/// "last weekday", "15th") into a rule with its implied frequency
+fn parse_modifiers(words: &[&str]) -> Result<Option<RecurrenceRule>, String> {
+    let unknown = || format!("Couldn't understand \"{}\"", words.join(" "));
+
+    match words {
+        [] => Ok(None),
+        ["weekday" | "weekdays"] => {
+            let mut rule = RecurrenceRule::new(Frequency::Weekly);
+            rule.by_day = weekdays(&[1, 2, 3, 4, 5]);
+            Ok(Some(rule))
+        }
+        ["weekend" | "weekends"] => {
+            let mut rule = RecurrenceRule::new(Frequency::Weekly);
+            rule.by_day = weekdays(&[6, 7]);
+            Ok(Some(rule))
+        }
+        [first, rest @ ..] if parse_ordinal(first).is_some() => {
+            let ordinal = parse_ordinal(first).unwrap_or(1);
+            let mut rule = RecurrenceRule::new(Frequency::Monthly);
+            match rest {
+                [] | ["day"] => rule.by_month_day = vec![ordinal],
+                ["weekday"] if ordinal <= 5 => {
+                    rule.by_day = weekdays(&[1, 2, 3, 4, 5]);
+                    rule.by_set_pos = Some(ordinal);
+                }
+                [day] if ordinal <= 5 && parse_weekday(day).is_some() => {
+                    rule.by_day = vec![WeekdaySpec {
+                        weekday: parse_weekday(day).unwrap_or(1),
+                        ordinal: Some(ordinal),
+                    }];
+                }
+                _ => return Err(unknown()),
+            }
+            Ok(Some(rule))
+        }
+        days => {
+            let mut parsed: Vec<u8> = days
+                .iter()
+                .map(|day| parse_weekday(day))
+                .collect::<Option<_>>()
+                .ok_or_else(unknown)?;
+            parsed.sort_unstable();
+            parsed.dedup();
+            let mut rule = RecurrenceRule::new(Frequency::Weekly);
+            rule.by_day = weekdays(&parsed);
+            Ok(Some(rule))
+        }
+    }
+}
+
+/// Removes a trailing "after completion" style suffix, returning whether it was present
+fn strip_after_completion(words: &mut Vec<&str>) -> bool {
+    let Some(pos) = words.iter().rposition(|word| *word == "after" || *word == "from") else {
+        return false;
+    };
+    let tail = &words[pos + 1..];
+    let is_completion = tail
+        .iter()
+        .any(|word| word.starts_with("complet") || word.starts_with("finish") || *word == "done");
+    if is_completion {
+        words.truncate(pos);
+    }
+    is_completion
+}
+
+/// Removes a trailing "of the month" style suffix, which only restates the frequency
+fn strip_month_context(words: &mut Vec<&str>) {
+    const SUFFIXES: &[&[&str]] = &[
+        &["of", "the", "month"],
+        &["of", "every", "month"],
+        &["of", "each", "month"],
+        &["of", "month"],
+        &["a", "month"],
+        &["per", "month"],
+    ];
+    for suffix in SUFFIXES {
+        if words.len() > suffix.len() && words.ends_with(suffix) {
+            words.truncate(words.len() - suffix.len());
+            return;
+        }
+    }
+}
+
+/// Parses a natural-language phrase such as "every 2nd Tuesday",
+/// "last weekday of the month", or "every 3 days after completion".
+///
+/// An ordinal followed by a weekday is a monthly rule ("2nd Tuesday" of the
+/// month); use "every other Tuesday" or "every fortnight" for a fortnightly one.
+pub fn parse_phrase(phrase: &str) -> Result<RecurrenceRule, String> {
+    if phrase.chars().count() > MAX_PHRASE_LEN {
+        return Err(format!("Phrase must be at most {} characters", MAX_PHRASE_LEN));
+    }
+
+    let lowered = phrase.trim().to_lowercase().replace([',', '.', '\''], " ");
+    let mut words: Vec<&str> = lowered.split_whitespace().collect();
+
+    let after_completion = strip_after_completion(&mut words);
+    strip_month_context(&mut words);
+    words.retain(|word| !FILLER_WORDS.contains(word));
+
+    if words.is_empty() {
+        return Err("Enter a recurrence such as \"every Monday\"".to_string());
+    }
+
+    let mut rule = match words.as_slice() {
+        ["biweekly" | "fortnightly" | "fortnight"] => RecurrenceRule {
+            interval: 2,
+            ..RecurrenceRule::new(Frequency::Weekly)
+        },
+        // "twice a week" doesn't say which days
+        ["twice" | "thrice", ..] => {
+            return Err("Name the days instead, e.g. \"every Monday and Thursday\"".to_string());
+        }
+        words => {
+            let (interval, rest) = match words.split_first() {
+                Some((first, rest)) if parse_count(first).is_some() && !rest.is_empty() => {
+                    (parse_count(first).unwrap_or(1), rest)
+                }
+                _ => (1, words),
+            };
+
+            let (unit, rest) = match rest.split_first() {
+                Some((first, rest)) if parse_unit(first).is_some() => (parse_unit(first), rest),
+                _ => (None, rest),
+            };
+
+            let modifiers = parse_modifiers(rest)?;
+            let mut rule = match (unit, modifiers) {
+                (Some(freq), None) => RecurrenceRule::new(freq),
+                (None, Some(rule)) => rule,
+                (Some(freq), Some(rule)) if rule.freq == freq => rule,
+                _ => return Err(format!("Couldn't understand \"{}\"", phrase.trim())),
+            };
+            rule.interval = interval;
+            rule
+        }
+    };
+
+    rule.after_completion = after_completion;
+    Ok(rule)
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecurrencePreview {
+    rule: RecurrenceRule,
+    rrule: String,
+    next_occurrences: Vec<String>,
+}
+
+#[tauri::command]
+pub fn parse_recurrence(phrase: String) -> Result<RecurrenceRule, String> {
+    parse_phrase(&phrase)
+}
+
+/// Parses a phrase and shows the next five occurrences after `from` (YYYY-MM-DD, defaults to today)
+#[tauri::command]
+pub fn preview_recurrence(phrase: String, from: Option<String>) -> Result<RecurrencePreview, String> {
+    let rule = parse_phrase(&phrase)?;
+    let start = match from {
+        Some(value) => NaiveDate::parse_from_str(&value, DATE_FORMAT)
+            .map_err(|_| format!("Invalid date: {}", value))?,
+        None => Local::now().date_naive(),
+    };
+
+    let next_occurrences = rule
+        .occurrences_after(start, PREVIEW_COUNT)
+        .into_iter()
+        .map(|date| date.format(DATE_FORMAT).to_string())
+        .collect();
+
+    Ok(RecurrencePreview {
+        rrule: rule.to_rrule(),
+        rule,
+        next_occurrences,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, DATE_FORMAT).unwrap()
+    }
+
+    fn next(phrase: &str, from: &str, count: usize) -> Vec<String> {
+        parse_phrase(phrase)
+            .unwrap()
+            .occurrences_after(date(from), count)
+            .into_iter()
+            .map(|d| d.format(DATE_FORMAT).to_string())
+            .collect()
+    }
+
+    fn rrule(phrase: &str) -> String {
+        parse_phrase(phrase).unwrap().to_rrule()
+    }
+
+    #[test]
+    fn second_tuesday_is_monthly() {
+        assert_eq!(rrule("every 2nd Tuesday"), "FREQ=MONTHLY;BYDAY=2TU");
+        assert_eq!(
+            next("every 2nd Tuesday", "2026-10-14", 3),
+            ["2026-11-10", "2026-12-08", "2027-01-12"]
+        );
+    }
+
+    #[test]
+    fn last_weekday_of_the_month_uses_set_pos() {
+        assert_eq!(
+            rrule("last weekday of the month"),
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1"
+        );
+        // October 31st 2026 is a Saturday
+        assert_eq!(
+            next("last weekday of the month", "2026-10-14", 3),
+            ["2026-10-30", "2026-11-30", "2026-12-31"]
+        );
+    }
+
+    #[test]
+    fn first_weekday_uses_set_pos() {
+        assert_eq!(rrule("first weekday"), "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1");
+        assert_eq!(
+            next("first weekday", "2026-10-14", 3),
+            ["2026-11-02", "2026-12-01", "2027-01-01"]
+        );
+    }
+
+    #[test]
+    fn every_three_days_after_completion() {
+        let rule = parse_phrase("every 3 days after completion").unwrap();
+        assert!(rule.after_completion);
+        assert_eq!(rule.to_rrule(), "FREQ=DAILY;INTERVAL=3");
+        assert_eq!(
+            next("every 3 days after completion", "2026-10-14", 3),
+            ["2026-10-17", "2026-10-20", "2026-10-23"]
+        );
+    }
+
+    #[test]
+    fn monthly_on_the_31st_skips_short_months() {
+        assert_eq!(rrule("monthly on the 31st"), "FREQ=MONTHLY;BYMONTHDAY=31");
+        assert_eq!(
+            next("monthly on the 31st", "2026-01-15", 3),
+            ["2026-01-31", "2026-03-31", "2026-05-31"]
+        );
+        // Plain "monthly" keeps the start date's day the same way
+        assert_eq!(next("monthly", "2026-01-31", 2), ["2026-03-31", "2026-05-31"]);
+    }
+
+    #[test]
+    fn last_day_of_the_month_follows_month_length() {
+        assert_eq!(
+            next("last day of the month", "2026-01-31", 3),
+            ["2026-02-28", "2026-03-31", "2026-04-30"]
+        );
+    }
+
+    #[test]
+    fn yearly_on_february_29th_waits_for_leap_years() {
+        assert_eq!(next("yearly", "2024-02-29", 2), ["2028-02-29", "2032-02-29"]);
+    }
+
+    #[test]
+    fn intervals() {
+        assert_eq!(rrule("every 2 weeks"), "FREQ=WEEKLY;INTERVAL=2");
+        assert_eq!(next("every 2 weeks", "2026-10-14", 2), ["2026-10-28", "2026-11-11"]);
+        assert_eq!(rrule("every other Tuesday"), "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU");
+        // Weeks count from the start date's week, as in RRULE
+        assert_eq!(
+            next("every other Tuesday", "2026-10-14", 2),
+            ["2026-10-27", "2026-11-10"]
+        );
+        assert_eq!(rrule("every three months"), "FREQ=MONTHLY;INTERVAL=3");
+        assert_eq!(rrule("fortnightly"), "FREQ=WEEKLY;INTERVAL=2");
+        assert_eq!(rrule("every fortnight"), "FREQ=WEEKLY;INTERVAL=2");
+    }
+
+    #[test]
+    fn weekdays_are_sorted_and_deduped() {
+        assert_eq!(rrule("every Friday, Monday and Friday"), "FREQ=WEEKLY;BYDAY=MO,FR");
+        assert_eq!(
+            next("every Monday and Friday", "2026-10-14", 3),
+            ["2026-10-16", "2026-10-19", "2026-10-23"]
+        );
+    }
+
+    #[test]
+    fn rejects_unclear_phrases() {
+        assert!(parse_phrase("").is_err());
+        assert!(parse_phrase("twice a week").is_err());
+        assert!(parse_phrase("every 1000 days").is_err());
+        assert!(parse_phrase("every 2nd month on Tuesday").is_err());
+        assert!(parse_phrase(&"every day ".repeat(50)).is_err());
+    }
+}
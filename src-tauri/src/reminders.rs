@@ -0,0 +1,374 @@
+use std::thread;
+use std::time::Duration;
+
+use chrono::{Local, NaiveDate, NaiveTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::app_lock::AppLock;
+use crate::storage::TaskStore;
+use crate::store::emit_to_main;
+use crate::work_hours;
+
+// How often the scheduler checks for due reminders
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+// Snooze bounds (minutes)
+const DEFAULT_SNOOZE_MINUTES: u32 = 10;
+const MAX_SNOOZE_MINUTES: u32 = 24 * 60;
+
+// Delivered one-off reminders stay snoozable for a day before being dropped
+const DELIVERED_RETENTION_SECS: i64 = 24 * 60 * 60;
+
+const DAY_SECS: i64 = 24 * 60 * 60;
+
+// Reminders whose task is still open, with the task's title
+const SELECT_REMINDER: &str = "SELECT r.id, r.task_id, t.title, r.fire_at, r.repeat, r.snoozed_until, r.delivered
+    FROM reminders r JOIN tasks t ON t.id = r.task_id
+    WHERE t.completed = 0 AND t.deleted = 0";
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Repeat {
+    Daily,
+    Weekly,
+}
+
+impl Repeat {
+    fn period_secs(self) -> i64 {
+        match self {
+            Repeat::Daily => DAY_SECS,
+            Repeat::Weekly => 7 * DAY_SECS,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Repeat::Daily => "daily",
+            Repeat::Weekly => "weekly",
+        }
+    }
+
+    fn from_column(value: Option<String>) -> Option<Self> {
+        match value.as_deref() {
+            Some("daily") => Some(Repeat::Daily),
+            Some("weekly") => Some(Repeat::Weekly),
+            _ => None,
+        }
+    }
+}
+
+/// A task's reminder. Each task has at most one; it follows the due date
+/// and can be moved with `schedule_reminder`.
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reminder {
+    id: String,
+    task_id: String,
+    /// The task's current title
+    title: String,
+    /// Scheduled time, Unix seconds
+    fire_at: i64,
+    repeat: Option<Repeat>,
+    /// Overrides `fire_at` once, without shifting a repeating schedule
+    snoozed_until: Option<i64>,
+    /// A one-off reminder that has already been shown
+    delivered: bool,
+}
+
+impl Reminder {
+    /// Marks the reminder as shown. Repeating reminders move to their next
+    /// occurrence after `now`; occurrences missed while the app was closed fire once.
+    fn mark_fired(&mut self, now: i64) {
+        if self.snoozed_until.take().is_some() && self.fire_at > now {
+            return;
+        }
+        match self.repeat {
+            Some(repeat) => {
+                let period = repeat.period_secs();
+                let missed = (now - self.fire_at).div_euclid(period) + 1;
+                self.fire_at += missed * period;
+            }
+            None => self.delivered = true,
+        }
+    }
+}
+
+/// Sent to the main window for each delivered reminder
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReminderFired {
+    reminder_id: String,
+    task_id: String,
+}
+
+fn reminder_from_row(row: &Row) -> rusqlite::Result<Reminder> {
+    Ok(Reminder {
+        id: row.get("id")?,
+        task_id: row.get("task_id")?,
+        title: row.get("title")?,
+        fire_at: row.get("fire_at")?,
+        repeat: Repeat::from_column(row.get("repeat")?),
+        snoozed_until: row.get("snoozed_until")?,
+        delivered: row.get("delivered")?,
+    })
+}
+
+fn get(conn: &Connection, id: &str) -> Result<Reminder, String> {
+    conn.query_row(&format!("{} AND r.id = ?1", SELECT_REMINDER), params![id], reminder_from_row)
+        .optional()
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Reminder not found".to_string())
+}
+
+/// When a due date (YYYY-MM-DD) reminds, at `at` local time that day, or
+/// None if that time has passed
+fn due_reminder_time(due_date: &str, at: NaiveTime, now: i64) -> Option<i64> {
+    let fire_at = NaiveDate::parse_from_str(due_date, "%Y-%m-%d")
+        .ok()?
+        .and_time(at)
+        .and_local_timezone(Local)
+        .earliest()?
+        .timestamp();
+    (fire_at > now).then_some(fire_at)
+}
+
+/// Replaces a task's reminder with one for its due date, at the start of the
+/// working day (`at`). The task store calls this when a due date is set,
+/// changed or cleared, and when a task is reopened.
+pub fn reset_for_due_date(conn: &Connection, task_id: &str, due_date: Option<&str>, at: NaiveTime) -> rusqlite::Result<()> {
+    cancel_for_task(conn, task_id)?;
+    let Some(fire_at) = due_date.and_then(|date| due_reminder_time(date, at, Utc::now().timestamp())) else {
+        return Ok(());
+    };
+    conn.execute(
+        "INSERT INTO reminders (id, task_id, fire_at, from_due_date) VALUES (?1, ?2, ?3, 1)",
+        params![uuid::Uuid::new_v4().to_string(), task_id, fire_at],
+    )?;
+    Ok(())
+}
+
+/// Moves pending due-date reminders to a new start of the working day.
+/// Reminders set with `schedule_reminder` keep their time.
+pub fn retime_due_reminders(conn: &Connection, at: NaiveTime) -> rusqlite::Result<()> {
+    let due: Vec<(String, String)> = {
+        let mut statement = conn.prepare(
+            "SELECT r.task_id, t.due_date FROM reminders r JOIN tasks t ON t.id = r.task_id
+             WHERE r.from_due_date = 1 AND r.delivered = 0 AND r.snoozed_until IS NULL
+                 AND t.due_date IS NOT NULL",
+        )?;
+        let rows = statement.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect::<rusqlite::Result<_>>()?
+    };
+    for (task_id, due_date) in due {
+        reset_for_due_date(conn, &task_id, Some(&due_date), at)?;
+    }
+    Ok(())
+}
+
+/// Removes a task's reminder once it is completed or deleted
+pub fn cancel_for_task(conn: &Connection, task_id: &str) -> rusqlite::Result<()> {
+    conn.execute("DELETE FROM reminders WHERE task_id = ?1", params![task_id])?;
+    Ok(())
+}
+
+/// Takes every reminder due at `now`, advancing or retiring it
+fn take_due(conn: &mut Connection, now: i64) -> rusqlite::Result<Vec<Reminder>> {
+    let tx = conn.transaction()?;
+    let due: Vec<Reminder> = {
+        let mut statement = tx.prepare(&format!(
+            "{} AND COALESCE(r.snoozed_until, CASE WHEN r.delivered THEN NULL ELSE r.fire_at END) <= ?1",
+            SELECT_REMINDER
+        ))?;
+        let rows = statement.query_map(params![now], reminder_from_row)?;
+        rows.collect::<rusqlite::Result<_>>()?
+    };
+
+    for reminder in &due {
+        let mut fired = reminder.clone();
+        fired.mark_fired(now);
+        tx.execute(
+            "UPDATE reminders SET fire_at = ?2, snoozed_until = ?3, delivered = ?4 WHERE id = ?1",
+            params![fired.id, fired.fire_at, fired.snoozed_until, fired.delivered],
+        )?;
+    }
+    tx.execute(
+        "DELETE FROM reminders WHERE delivered = 1 AND snoozed_until IS NULL AND fire_at < ?1",
+        params![now - DELIVERED_RETENTION_SECS],
+    )?;
+    tx.commit()?;
+    Ok(due)
+}
+
+fn notify(app: &AppHandle, reminder: &Reminder) {
+    // Don't reveal task titles on screen while the app is locked
+    let body = if app.state::<AppLock>().is_locked() {
+        "You have a task reminder"
+    } else {
+        reminder.title.as_str()
+    };
+    if let Err(_e) = app.notification().builder().title("Reminder").body(body).show() {
+        #[cfg(debug_assertions)]
+        eprintln!("Failed to show reminder notification: {:?}", _e);
+    }
+}
+
+/// Whether reminders may be shown now. Outside the work schedule's hours they
+/// wait, and everything that came due is shown when the next working day starts.
+fn in_working_hours(app: &AppHandle) -> bool {
+    work_hours::load_schedule(app)
+        .map(|schedule| schedule.is_working_time(Local::now().naive_local()))
+        .unwrap_or(true)
+}
+
+/// Spawns the scheduler thread. Each delivery is also sent to the main window
+/// as `reminder-fired`. Desktop notifications have no click callback, so the
+/// window selects the task the next time the app is brought forward, which
+/// is what clicking the notification does.
+pub fn spawn_scheduler(app: AppHandle) {
+    thread::spawn(move || loop {
+        if !in_working_hours(&app) {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+
+        let now = Utc::now().timestamp();
+        let due = app
+            .state::<TaskStore>()
+            .with_conn(|conn| take_due(conn, now).map_err(|e| e.to_string()));
+        match due {
+            Ok(due) => {
+                for reminder in &due {
+                    #[cfg(debug_assertions)]
+                    println!("Reminder fired for task {}", reminder.task_id);
+                    notify(&app, reminder);
+                    emit_to_main(
+                        &app,
+                        "reminder-fired",
+                        ReminderFired {
+                            reminder_id: reminder.id.clone(),
+                            task_id: reminder.task_id.clone(),
+                        },
+                    );
+                }
+            }
+            Err(_e) => {
+                #[cfg(debug_assertions)]
+                eprintln!("Failed to check reminders: {}", _e);
+            }
+        }
+        thread::sleep(POLL_INTERVAL);
+    });
+}
+
+#[tauri::command]
+pub fn list_reminders(store: State<'_, TaskStore>, lock: State<'_, AppLock>) -> Result<Vec<Reminder>, String> {
+    lock.ensure_unlocked()?;
+    store.with_conn(|conn| {
+        let mut statement = conn
+            .prepare(&format!("{} ORDER BY r.fire_at", SELECT_REMINDER))
+            .map_err(|e| e.to_string())?;
+        let reminders = statement
+            .query_map([], reminder_from_row)
+            .and_then(|rows| rows.collect())
+            .map_err(|e| e.to_string())?;
+        Ok(reminders)
+    })
+}
+
+/// Moves an open task's reminder to `fire_at` (Unix seconds), optionally repeating
+#[tauri::command]
+pub fn schedule_reminder(
+    store: State<'_, TaskStore>,
+    lock: State<'_, AppLock>,
+    task_id: String,
+    fire_at: i64,
+    repeat: Option<Repeat>,
+) -> Result<Reminder, String> {
+    lock.ensure_unlocked()?;
+    if fire_at <= Utc::now().timestamp() {
+        return Err("Reminder time must be in the future".to_string());
+    }
+
+    store.with_conn(|conn| {
+        let open: bool = conn
+            .query_row(
+                "SELECT completed = 0 AND deleted = 0 FROM tasks WHERE id = ?1",
+                params![task_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| e.to_string())?
+            .unwrap_or(false);
+        if !open {
+            return Err("Task not found".to_string());
+        }
+
+        conn.execute(
+            "INSERT INTO reminders (id, task_id, fire_at, repeat) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT (task_id) DO UPDATE SET
+                 fire_at = excluded.fire_at, repeat = excluded.repeat, snoozed_until = NULL, delivered = 0,
+                 from_due_date = 0",
+            params![
+                uuid::Uuid::new_v4().to_string(),
+                task_id,
+                fire_at,
+                repeat.map(Repeat::as_str)
+            ],
+        )
+        .map_err(|e| e.to_string())?;
+        let id: String = conn
+            .query_row("SELECT id FROM reminders WHERE task_id = ?1", params![task_id], |row| row.get(0))
+            .map_err(|e| e.to_string())?;
+        get(conn, &id)
+    })
+}
+
+#[tauri::command]
+pub fn cancel_reminder(store: State<'_, TaskStore>, lock: State<'_, AppLock>, id: String) -> Result<(), String> {
+    lock.ensure_unlocked()?;
+    store.with_conn(|conn| {
+        let changed = conn
+            .execute("DELETE FROM reminders WHERE id = ?1", params![id])
+            .map_err(|e| e.to_string())?;
+        if changed == 0 {
+            return Err("Reminder not found".to_string());
+        }
+        Ok(())
+    })
+}
+
+/// Shows the reminder again after `minutes` (default 10). Repeating reminders
+/// keep their regular schedule.
+#[tauri::command]
+pub fn snooze_reminder(
+    store: State<'_, TaskStore>,
+    lock: State<'_, AppLock>,
+    id: String,
+    minutes: Option<u32>,
+) -> Result<Reminder, String> {
+    lock.ensure_unlocked()?;
+
+    let minutes = minutes.unwrap_or(DEFAULT_SNOOZE_MINUTES);
+    if !(1..=MAX_SNOOZE_MINUTES).contains(&minutes) {
+        return Err(format!(
+            "Snooze must be between 1 and {} minutes",
+            MAX_SNOOZE_MINUTES
+        ));
+    }
+
+    store.with_conn(|conn| {
+        let changed = conn
+            .execute(
+                "UPDATE reminders SET snoozed_until = ?2 WHERE id = ?1",
+                params![id, Utc::now().timestamp() + i64::from(minutes) * 60],
+            )
+            .map_err(|e| e.to_string())?;
+        if changed == 0 {
+            return Err("Reminder not found".to_string());
+        }
+        get(conn, &id)
+    })
+}
@@ -0,0 +1,80 @@
+use serde::Serialize;
+use tauri::State;
+
+use crate::app_lock::AppLock;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    NotApplicable,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditCheck {
+    id: &'static str,
+    label: &'static str,
+    status: CheckStatus,
+    detail: String,
+}
+
+fn check(id: &'static str, label: &'static str, status: CheckStatus, detail: impl Into<String>) -> AuditCheck {
+    AuditCheck {
+        id,
+        label,
+        status,
+        detail: detail.into(),
+    }
+}
+
+/// Reports the security posture of this install so users can verify their setup.
+/// Checks for subsystems the app doesn't have are reported as not applicable
+/// rather than omitted, so the report shape stays stable.
+#[tauri::command]
+pub fn security_audit(lock: State<'_, AppLock>) -> Vec<AuditCheck> {
+    let (lock_enabled, auto_lock_minutes) = lock.configuration();
+
+    vec![
+        check(
+            "database_encryption",
+            "Local database encryption",
+            CheckStatus::Warn,
+            "Task titles and notes are stored unencrypted in tasks.db in the app data directory.",
+        ),
+        check(
+            "token_storage",
+            "Auth tokens in the keychain",
+            CheckStatus::Warn,
+            "The Supabase session is stored in the webview's local storage, not the OS keychain.",
+        ),
+        check(
+            "local_api",
+            "Local API disabled",
+            CheckStatus::Pass,
+            "The app does not expose a local HTTP API.",
+        ),
+        check(
+            "backup_encryption",
+            "Encrypted backups",
+            CheckStatus::NotApplicable,
+            "The app does not create local backups.",
+        ),
+        if lock_enabled {
+            check("app_lock", "App lock", CheckStatus::Pass, "A passcode is required at launch.")
+        } else {
+            check("app_lock", "App lock", CheckStatus::Warn, "No passcode is configured.")
+        },
+        match (lock_enabled, auto_lock_minutes) {
+            (true, Some(minutes)) => check(
+                "auto_lock",
+                "Auto-lock",
+                CheckStatus::Pass,
+                format!("The app locks after {} minutes of inactivity.", minutes),
+            ),
+            (true, None) => check("auto_lock", "Auto-lock", CheckStatus::Warn, "Auto-lock is turned off."),
+            (false, _) => check("auto_lock", "Auto-lock", CheckStatus::Warn, "Auto-lock requires an app passcode."),
+        },
+    ]
+}
@@ -0,0 +1,138 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, State};
+
+use crate::app_lock::AppLock;
+use crate::store::{self, now_secs};
+
+const SESSION_FILE: &str = "session_policy.json";
+
+const MAX_SESSION_DAYS: u32 = 365;
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// How long a sign-in stays valid before the backend requires re-authentication
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SessionPolicy {
+    max_age_days: Option<u32>,
+    reauth_every_launch: bool,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct SessionRecord {
+    policy: SessionPolicy,
+    signed_in_at: Option<u64>,
+}
+
+struct SessionInner {
+    record: SessionRecord,
+    signed_in_this_launch: bool,
+}
+
+/// Backend-enforced session lifetime. The Supabase session itself lives in
+/// the webview; this decides whether it may still be used.
+pub struct SessionGuard {
+    path: PathBuf,
+    inner: Mutex<SessionInner>,
+}
+
+impl SessionGuard {
+    pub fn load(app: &AppHandle) -> Result<Self, String> {
+        let path = store::config_path(app, SESSION_FILE)?;
+        let record: SessionRecord = store::read_json(&path);
+
+        Ok(Self {
+            path,
+            inner: Mutex::new(SessionInner {
+                record,
+                signed_in_this_launch: false,
+            }),
+        })
+    }
+
+    /// Forgets the in-memory policy and sign-in after the on-disk record was erased
+    pub fn reset(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.record = SessionRecord::default();
+        inner.signed_in_this_launch = false;
+    }
+
+    fn save(&self, record: &SessionRecord) -> Result<(), String> {
+        store::write_json(&self.path, record)
+    }
+}
+
+fn reauth_required(inner: &SessionInner) -> bool {
+    let policy = inner.record.policy;
+
+    if policy.reauth_every_launch && !inner.signed_in_this_launch {
+        return true;
+    }
+
+    match (policy.max_age_days, inner.record.signed_in_at) {
+        (None, _) => false,
+        // Fail closed: a lifetime is configured but we never saw the sign-in
+        (Some(_), None) => true,
+        (Some(days), Some(signed_in_at)) => {
+            now_secs().saturating_sub(signed_in_at) > u64::from(days) * SECS_PER_DAY
+        }
+    }
+}
+
+#[tauri::command]
+pub fn record_sign_in(guard: State<'_, SessionGuard>) -> Result<(), String> {
+    let mut inner = guard.inner.lock().unwrap();
+    inner.record.signed_in_at = Some(now_secs());
+    inner.signed_in_this_launch = true;
+    guard.save(&inner.record)
+}
+
+#[tauri::command]
+pub fn record_sign_out(guard: State<'_, SessionGuard>) -> Result<(), String> {
+    let mut inner = guard.inner.lock().unwrap();
+    inner.record.signed_in_at = None;
+    inner.signed_in_this_launch = false;
+    guard.save(&inner.record)
+}
+
+/// Returns true if the stored session must not be used and the user has to sign in again
+#[tauri::command]
+pub fn check_session(guard: State<'_, SessionGuard>) -> bool {
+    reauth_required(&guard.inner.lock().unwrap())
+}
+
+#[tauri::command]
+pub fn get_session_policy(guard: State<'_, SessionGuard>) -> SessionPolicy {
+    guard.inner.lock().unwrap().record.policy
+}
+
+/// Preferences are only reachable while signed in, so the current session is
+/// treated as starting now rather than failing closed for predating the policy
+#[tauri::command]
+pub fn set_session_policy(
+    guard: State<'_, SessionGuard>,
+    lock: State<'_, AppLock>,
+    policy: SessionPolicy,
+) -> Result<SessionPolicy, String> {
+    lock.ensure_unlocked()?;
+    if let Some(days) = policy.max_age_days {
+        if !(1..=MAX_SESSION_DAYS).contains(&days) {
+            return Err(format!("Session lifetime must be between 1 and {} days", MAX_SESSION_DAYS));
+        }
+    }
+
+    let mut inner = guard.inner.lock().unwrap();
+    let previous = inner.record.policy;
+    if policy.max_age_days.is_some() && previous.max_age_days.is_none() && inner.record.signed_in_at.is_none() {
+        inner.record.signed_in_at = Some(now_secs());
+    }
+    if policy.reauth_every_launch && !previous.reauth_every_launch {
+        inner.signed_in_this_launch = true;
+    }
+    inner.record.policy = policy;
+    guard.save(&inner.record)?;
+    Ok(policy)
+}
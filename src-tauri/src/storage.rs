@@ -0,0 +1,932 @@
+use std::collections::HashSet;
+use std::fs;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+
+use crate::accessibility;
+use crate::app_lock::AppLock;
+use crate::reminders;
+use crate::tray;
+use crate::work_hours;
+
+const DB_FILE: &str = "tasks.db";
+
+// Field limits for task input
+const MAX_TITLE_LEN: usize = 500;
+const MAX_NOTES_LEN: usize = 10_000;
+const MAX_TAGS: usize = 20;
+const MAX_TAG_LEN: usize = 50;
+
+// Schema migrations, applied in order. PRAGMA user_version records how many have run.
+const MIGRATIONS: &[&str] = &[
+    "CREATE TABLE tasks (
+        id TEXT PRIMARY KEY NOT NULL,
+        title TEXT NOT NULL,
+        notes TEXT,
+        due_date TEXT,
+        completed INTEGER NOT NULL DEFAULT 0,
+        priority INTEGER NOT NULL DEFAULT 0,
+        tags TEXT NOT NULL DEFAULT '[]',
+        created_at INTEGER NOT NULL,
+        updated_at INTEGER NOT NULL
+    );
+    CREATE INDEX tasks_due_date ON tasks (completed, due_date);",
+    // Completion time, plus bookkeeping for mirroring tasks to Supabase:
+    // `dirty` rows have local changes not yet pushed, `deleted` rows are
+    // tombstones kept until their deletion has been pushed
+    "ALTER TABLE tasks ADD COLUMN completed_at INTEGER;
+    ALTER TABLE tasks ADD COLUMN deleted INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE tasks ADD COLUMN dirty INTEGER NOT NULL DEFAULT 1;
+    UPDATE tasks SET completed_at = updated_at WHERE completed = 1;
+    CREATE TABLE sync_state (
+        key TEXT PRIMARY KEY NOT NULL,
+        value TEXT NOT NULL
+    );",
+    // Reminders, at most one per task
+    "CREATE TABLE reminders (
+        id TEXT PRIMARY KEY NOT NULL,
+        task_id TEXT NOT NULL UNIQUE REFERENCES tasks (id) ON DELETE CASCADE,
+        fire_at INTEGER NOT NULL,
+        repeat TEXT,
+        snoozed_until INTEGER,
+        delivered INTEGER NOT NULL DEFAULT 0
+    );",
+    // Bumped on every local change; identifies the version a push confirms,
+    // since edits within the same second share an updated_at
+    "ALTER TABLE tasks ADD COLUMN revision INTEGER NOT NULL DEFAULT 0;",
+    // Reminders derived from a due date follow the work schedule's start time
+    "ALTER TABLE reminders ADD COLUMN from_due_date INTEGER NOT NULL DEFAULT 0;",
+];
+
+const SELECT_TASK: &str = "SELECT id, title, notes, due_date, completed, completed_at, priority, tags, created_at, updated_at
+    FROM tasks";
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    #[default]
+    None,
+    Low,
+    Medium,
+    High,
+}
+
+impl Priority {
+    fn from_level(level: i64) -> Self {
+        match level {
+            1 => Priority::Low,
+            2 => Priority::Medium,
+            3 => Priority::High,
+            _ => Priority::None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Task {
+    id: String,
+    title: String,
+    notes: Option<String>,
+    /// YYYY-MM-DD
+    due_date: Option<String>,
+    completed: bool,
+    priority: Priority,
+    tags: Vec<String>,
+    /// Unix seconds
+    completed_at: Option<i64>,
+    created_at: i64,
+    updated_at: i64,
+}
+
+/// A local change that hasn't been pushed to Supabase yet
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingChange {
+    id: String,
+    title: String,
+    /// Unix seconds
+    completed_at: Option<i64>,
+    created_at: i64,
+    /// Identifies this version of the row when the push is confirmed
+    revision: i64,
+    deleted: bool,
+}
+
+/// A pushed change, confirmed with the version that was sent
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncedChange {
+    id: String,
+    revision: i64,
+}
+
+/// A row of the Supabase `tasks` table, with times as Unix seconds
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteTask {
+    id: String,
+    name: String,
+    created_at: i64,
+    #[serde(default)]
+    completed_at: Option<i64>,
+    #[serde(default)]
+    deleted_at: Option<i64>,
+}
+
+/// Editable task fields, used for both create and update
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskInput {
+    title: String,
+    #[serde(default)]
+    notes: Option<String>,
+    #[serde(default)]
+    due_date: Option<String>,
+    #[serde(default)]
+    priority: Priority,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+impl TaskInput {
+    /// A task with just a title and due date, as captured by quick add
+    pub fn new(title: String, due_date: Option<String>) -> Self {
+        Self {
+            title,
+            notes: None,
+            due_date,
+            priority: Priority::None,
+            tags: Vec::new(),
+        }
+    }
+}
+
+/// Validated input, ready to bind to a statement
+struct TaskFields {
+    title: String,
+    notes: Option<String>,
+    due_date: Option<String>,
+    priority: i64,
+    /// JSON array
+    tags: String,
+}
+
+impl TaskInput {
+    fn validate(self) -> Result<TaskFields, String> {
+        let title = validate_title(&self.title)?;
+
+        let notes = self
+            .notes
+            .map(|notes| notes.trim().to_string())
+            .filter(|notes| !notes.is_empty());
+        if notes.as_ref().is_some_and(|notes| notes.chars().count() > MAX_NOTES_LEN) {
+            return Err(format!("Notes must be at most {} characters", MAX_NOTES_LEN));
+        }
+
+        let due_date = match self.due_date.filter(|date| !date.is_empty()) {
+            Some(date) => {
+                NaiveDate::parse_from_str(&date, "%Y-%m-%d").map_err(|_| format!("Invalid due date: {}", date))?;
+                Some(date)
+            }
+            None => None,
+        };
+
+        let mut tags: Vec<String> = Vec::new();
+        for tag in self.tags {
+            let tag = tag.trim().to_string();
+            if tag.is_empty() || tags.contains(&tag) {
+                continue;
+            }
+            if tag.chars().count() > MAX_TAG_LEN {
+                return Err(format!("Tags must be at most {} characters", MAX_TAG_LEN));
+            }
+            tags.push(tag);
+        }
+        if tags.len() > MAX_TAGS {
+            return Err(format!("A task can have at most {} tags", MAX_TAGS));
+        }
+        let tags = serde_json::to_string(&tags).map_err(|e| e.to_string())?;
+
+        Ok(TaskFields {
+            title,
+            notes,
+            due_date,
+            priority: self.priority as i64,
+            tags,
+        })
+    }
+}
+
+fn validate_title(title: &str) -> Result<String, String> {
+    let title = title.trim();
+    if title.is_empty() || title.chars().count() > MAX_TITLE_LEN {
+        return Err(format!("Title must be between 1 and {} characters", MAX_TITLE_LEN));
+    }
+    Ok(title.to_string())
+}
+
+/// Local SQLite task store, kept in the app data directory so tasks are
+/// available offline
+pub struct TaskStore {
+    /// None once the store has been closed for erasure
+    conn: Mutex<Option<Connection>>,
+    /// Local time due-date reminders fire, the start of the working day
+    reminder_time: Mutex<NaiveTime>,
+}
+
+impl TaskStore {
+    /// Opens (or creates) the database and applies pending migrations
+    pub fn open(app: &AppHandle) -> Result<Self, String> {
+        let dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+        let mut conn = Connection::open(dir.join(DB_FILE)).map_err(|e| e.to_string())?;
+        // SQLite leaves foreign keys off unless asked; reminders rely on the cascade
+        conn.pragma_update(None, "foreign_keys", true).map_err(|e| e.to_string())?;
+        migrate(&mut conn).map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            conn: Mutex::new(Some(conn)),
+            reminder_time: Mutex::new(work_hours::load_schedule(app)?.start_time()),
+        })
+    }
+
+    fn reminder_time(&self) -> NaiveTime {
+        *self.reminder_time.lock().unwrap()
+    }
+
+    /// Changes when due-date reminders fire and moves the pending ones
+    pub fn set_reminder_time(&self, at: NaiveTime) -> Result<(), String> {
+        *self.reminder_time.lock().unwrap() = at;
+        self.with_conn(|conn| reminders::retime_due_reminders(conn, at).map_err(|e| e.to_string()))
+    }
+
+    /// Closes the database file so it can be deleted. Every later call fails
+    /// until the app restarts.
+    pub fn close(&self) {
+        let Some(conn) = self.conn.lock().unwrap().take() else {
+            return;
+        };
+        if let Err((_, _e)) = conn.close() {
+            #[cfg(debug_assertions)]
+            eprintln!("Failed to close task store: {:?}", _e);
+        }
+    }
+
+    /// Runs `f` with the open connection
+    pub(crate) fn with_conn<T>(&self, f: impl FnOnce(&mut Connection) -> Result<T, String>) -> Result<T, String> {
+        let mut conn = self.conn.lock().unwrap();
+        let conn = conn.as_mut().ok_or_else(|| "Task store is closed".to_string())?;
+        f(conn)
+    }
+
+    pub fn list(&self) -> Result<Vec<Task>, String> {
+        self.with_conn(|conn| {
+            let mut statement = conn
+                .prepare(&format!(
+                    "{} WHERE deleted = 0
+                     ORDER BY completed, due_date IS NULL, due_date, priority DESC, created_at",
+                    SELECT_TASK
+                ))
+                .map_err(|e| e.to_string())?;
+            let tasks = statement
+                .query_map([], task_from_row)
+                .and_then(|rows| rows.collect())
+                .map_err(|e| e.to_string())?;
+            Ok(tasks)
+        })
+    }
+
+    /// Counts incomplete tasks due on or before `date` (YYYY-MM-DD)
+    pub fn count_due(&self, date: &str) -> Result<u32, String> {
+        self.with_conn(|conn| {
+            conn.query_row(
+                "SELECT COUNT(*) FROM tasks WHERE deleted = 0 AND completed = 0 AND due_date <= ?1",
+                params![date],
+                |row| row.get(0),
+            )
+            .map_err(|e| e.to_string())
+        })
+    }
+
+    pub fn create(&self, input: TaskInput) -> Result<Task, String> {
+        let fields = input.validate()?;
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = Utc::now().timestamp();
+        let reminder_time = self.reminder_time();
+
+        self.with_conn(|conn| {
+            conn.execute(
+                "INSERT INTO tasks (id, title, notes, due_date, priority, tags, created_at, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)",
+                params![id, fields.title, fields.notes, fields.due_date, fields.priority, fields.tags, now],
+            )
+            .and_then(|_| reminders::reset_for_due_date(conn, &id, fields.due_date.as_deref(), reminder_time))
+            .map_err(|e| e.to_string())?;
+            get(conn, &id)
+        })
+    }
+
+    pub fn update(&self, id: &str, input: TaskInput) -> Result<Task, String> {
+        let fields = input.validate()?;
+        let reminder_time = self.reminder_time();
+
+        self.with_conn(|conn| {
+            let previous_due: Option<String> = conn
+                .query_row(
+                    "SELECT due_date FROM tasks WHERE id = ?1 AND deleted = 0",
+                    params![id],
+                    |row| row.get(0),
+                )
+                .optional()
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| "Task not found".to_string())?;
+
+            conn.execute(
+                "UPDATE tasks SET title = ?2, notes = ?3, due_date = ?4, priority = ?5, tags = ?6,
+                     updated_at = ?7, revision = revision + 1, dirty = 1
+                 WHERE id = ?1 AND deleted = 0",
+                params![
+                    id,
+                    fields.title,
+                    fields.notes,
+                    fields.due_date,
+                    fields.priority,
+                    fields.tags,
+                    Utc::now().timestamp()
+                ],
+            )
+            .map_err(|e| e.to_string())?;
+            if previous_due != fields.due_date {
+                reminders::reset_for_due_date(conn, id, fields.due_date.as_deref(), reminder_time)
+                    .map_err(|e| e.to_string())?;
+            }
+            get(conn, id)
+        })
+    }
+
+    /// Deletes a task. The row stays as a tombstone until the deletion has
+    /// been pushed to Supabase.
+    pub fn delete(&self, id: &str) -> Result<(), String> {
+        self.with_conn(|conn| {
+            let changed = conn
+                .execute(
+                    "UPDATE tasks SET deleted = 1, dirty = 1, updated_at = ?2, revision = revision + 1
+                     WHERE id = ?1 AND deleted = 0",
+                    params![id, Utc::now().timestamp()],
+                )
+                .map_err(|e| e.to_string())?;
+            if changed == 0 {
+                return Err("Task not found".to_string());
+            }
+            reminders::cancel_for_task(conn, id).map_err(|e| e.to_string())
+        })
+    }
+
+    pub fn toggle_complete(&self, id: &str) -> Result<Task, String> {
+        let reminder_time = self.reminder_time();
+        self.with_conn(|conn| {
+            let changed = conn
+                .execute(
+                    "UPDATE tasks SET completed = NOT completed,
+                         completed_at = CASE WHEN completed THEN NULL ELSE ?2 END,
+                         updated_at = ?2, revision = revision + 1, dirty = 1
+                     WHERE id = ?1 AND deleted = 0",
+                    params![id, Utc::now().timestamp()],
+                )
+                .map_err(|e| e.to_string())?;
+            if changed == 0 {
+                return Err("Task not found".to_string());
+            }
+            let task = get(conn, id)?;
+            reset_reminder(conn, &task.id, task.completed, task.due_date.as_deref(), reminder_time)
+                .map_err(|e| e.to_string())?;
+            Ok(task)
+        })
+    }
+
+    /// The account whose tasks the store holds, if anyone is signed in
+    pub fn owner(&self) -> Result<Option<String>, String> {
+        self.with_conn(|conn| owner(conn).map_err(|e| e.to_string()))
+    }
+
+    /// Removes every local task and forgets the owner, on sign-out
+    pub fn clear(&self) -> Result<(), String> {
+        self.with_conn(|conn| {
+            conn.execute_batch("DELETE FROM tasks; DELETE FROM sync_state WHERE key = 'user_id';")
+                .map_err(|e| e.to_string())
+        })
+    }
+
+    /// Returns the changes to push for `user_id`. The store is claimed for that
+    /// account first, so tasks left by another account are never pushed under it.
+    pub fn pending_changes(&self, user_id: &str) -> Result<Vec<PendingChange>, String> {
+        self.with_conn(|conn| {
+            claim(conn, user_id).map_err(|e| e.to_string())?;
+            let mut statement = conn
+                .prepare(
+                    "SELECT id, title, completed_at, created_at, revision, deleted FROM tasks WHERE dirty = 1",
+                )
+                .map_err(|e| e.to_string())?;
+            let changes = statement
+                .query_map([], |row| {
+                    Ok(PendingChange {
+                        id: row.get("id")?,
+                        title: row.get("title")?,
+                        completed_at: row.get("completed_at")?,
+                        created_at: row.get("created_at")?,
+                        revision: row.get("revision")?,
+                        deleted: row.get("deleted")?,
+                    })
+                })
+                .and_then(|rows| rows.collect())
+                .map_err(|e| e.to_string())?;
+            Ok(changes)
+        })
+    }
+
+    /// Clears the pending flag for pushed changes and drops pushed tombstones.
+    /// Rows edited again since they were read keep their flag.
+    pub fn mark_synced(&self, changes: &[SyncedChange]) -> Result<(), String> {
+        self.with_conn(|conn| {
+            let tx = conn.transaction().map_err(|e| e.to_string())?;
+            for change in changes {
+                tx.execute(
+                    "DELETE FROM tasks WHERE id = ?1 AND revision = ?2 AND deleted = 1",
+                    params![change.id, change.revision],
+                )
+                .and_then(|_| {
+                    tx.execute(
+                        "UPDATE tasks SET dirty = 0 WHERE id = ?1 AND revision = ?2",
+                        params![change.id, change.revision],
+                    )
+                })
+                .map_err(|e| e.to_string())?;
+            }
+            tx.commit().map_err(|e| e.to_string())
+        })
+    }
+
+    /// Applies the signed-in user's Supabase rows. Rows with unpushed local
+    /// changes keep the local version; other rows take the remote one, and
+    /// rows that no longer exist remotely are removed. Returns how many tasks
+    /// were new to this device.
+    pub fn merge_remote(&self, user_id: &str, remote: Vec<RemoteTask>) -> Result<usize, String> {
+        let reminder_time = self.reminder_time();
+        self.with_conn(|conn| {
+            let tx = conn.transaction().map_err(|e| e.to_string())?;
+            let imported = merge_remote(&tx, user_id, remote, reminder_time).map_err(|e| e.to_string())?;
+            tx.commit().map_err(|e| e.to_string())?;
+            Ok(imported)
+        })
+    }
+}
+
+fn owner(conn: &Connection) -> rusqlite::Result<Option<String>> {
+    conn.query_row("SELECT value FROM sync_state WHERE key = 'user_id'", [], |row| row.get(0))
+        .optional()
+}
+
+/// Records `user_id` as the owner of the local tasks
+fn claim(conn: &Connection, user_id: &str) -> rusqlite::Result<()> {
+    if owner(conn)?.is_some_and(|owner| owner != user_id) {
+        // A different account signed in on this device; the previous account's tasks aren't theirs
+        conn.execute("DELETE FROM tasks", [])?;
+    }
+    conn.execute(
+        "INSERT OR REPLACE INTO sync_state (key, value) VALUES ('user_id', ?1)",
+        params![user_id],
+    )?;
+    Ok(())
+}
+
+fn merge_remote(
+    conn: &Connection,
+    user_id: &str,
+    remote: Vec<RemoteTask>,
+    reminder_time: NaiveTime,
+) -> rusqlite::Result<usize> {
+    claim(conn, user_id)?;
+
+    let now = Utc::now().timestamp();
+    let mut remote_ids = HashSet::new();
+    let mut imported = 0;
+    for task in remote {
+        let Ok(title) = validate_title(&task.name) else {
+            continue;
+        };
+        remote_ids.insert(task.id.clone());
+
+        let dirty: Option<bool> = conn
+            .query_row("SELECT dirty FROM tasks WHERE id = ?1", params![task.id], |row| row.get(0))
+            .optional()?;
+        // Completing a task also sets deleted_at, so deleted_at alone means it was deleted
+        let removed = task.deleted_at.is_some() && task.completed_at.is_none();
+        match dirty {
+            Some(true) => {}
+            Some(false) if removed => {
+                conn.execute("DELETE FROM tasks WHERE id = ?1", params![task.id])?;
+            }
+            Some(false) => {
+                let (was_completed, due_date): (bool, Option<String>) = conn.query_row(
+                    "SELECT completed, due_date FROM tasks WHERE id = ?1",
+                    params![task.id],
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )?;
+                let completed = task.completed_at.is_some();
+                conn.execute(
+                    "UPDATE tasks SET title = ?2, completed = ?3, completed_at = ?4 WHERE id = ?1",
+                    params![task.id, title, completed, task.completed_at],
+                )?;
+                if completed != was_completed {
+                    reset_reminder(conn, &task.id, completed, due_date.as_deref(), reminder_time)?;
+                }
+            }
+            None if removed => {}
+            None => {
+                // Supabase has no due dates, so a task is due on the day it was created
+                let due_date = DateTime::from_timestamp(task.created_at, 0)
+                    .map(|created| created.with_timezone(&Local).format("%Y-%m-%d").to_string());
+                conn.execute(
+                    "INSERT INTO tasks (id, title, due_date, completed, completed_at, created_at, updated_at, dirty)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 0)",
+                    params![
+                        task.id,
+                        title,
+                        due_date,
+                        task.completed_at.is_some(),
+                        task.completed_at,
+                        task.created_at,
+                        now
+                    ],
+                )?;
+                reset_reminder(conn, &task.id, task.completed_at.is_some(), due_date.as_deref(), reminder_time)?;
+                imported += 1;
+            }
+        }
+    }
+
+    let mut statement = conn.prepare("SELECT id FROM tasks WHERE dirty = 0")?;
+    let synced: Vec<String> = statement.query_map([], |row| row.get(0))?.collect::<Result<_, _>>()?;
+    for id in synced.iter().filter(|id| !remote_ids.contains(*id)) {
+        conn.execute("DELETE FROM tasks WHERE id = ?1", params![id])?;
+    }
+    Ok(imported)
+}
+
+/// Cancels a completed task's reminder, or restores an open task's due date reminder
+fn reset_reminder(
+    conn: &Connection,
+    id: &str,
+    completed: bool,
+    due_date: Option<&str>,
+    reminder_time: NaiveTime,
+) -> rusqlite::Result<()> {
+    if completed {
+        reminders::cancel_for_task(conn, id)
+    } else {
+        reminders::reset_for_due_date(conn, id, due_date, reminder_time)
+    }
+}
+
+fn migrate(conn: &mut Connection) -> rusqlite::Result<()> {
+    let applied: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    for (index, sql) in MIGRATIONS.iter().enumerate().skip(applied.max(0) as usize) {
+        let tx = conn.transaction()?;
+        tx.execute_batch(sql)?;
+        tx.pragma_update(None, "user_version", index as i64 + 1)?;
+        tx.commit()?;
+        #[cfg(debug_assertions)]
+        println!("Applied task store migration {}", index + 1);
+    }
+    Ok(())
+}
+
+fn get(conn: &Connection, id: &str) -> Result<Task, String> {
+    conn.query_row(&format!("{} WHERE id = ?1", SELECT_TASK), params![id], task_from_row)
+        .map_err(|e| e.to_string())
+}
+
+fn task_from_row(row: &Row) -> rusqlite::Result<Task> {
+    let tags: String = row.get("tags")?;
+    Ok(Task {
+        id: row.get("id")?,
+        title: row.get("title")?,
+        notes: row.get("notes")?,
+        due_date: row.get("due_date")?,
+        completed: row.get("completed")?,
+        completed_at: row.get("completed_at")?,
+        priority: Priority::from_level(row.get("priority")?),
+        tags: serde_json::from_str(&tags).unwrap_or_default(),
+        created_at: row.get("created_at")?,
+        updated_at: row.get("updated_at")?,
+    })
+}
+
+#[tauri::command]
+pub fn list_tasks(store: State<'_, TaskStore>, lock: State<'_, AppLock>) -> Result<Vec<Task>, String> {
+    lock.ensure_unlocked()?;
+    store.list()
+}
+
+#[tauri::command]
+pub fn create_task(
+    app: AppHandle,
+    store: State<'_, TaskStore>,
+    lock: State<'_, AppLock>,
+    task: TaskInput,
+) -> Result<Task, String> {
+    lock.ensure_unlocked()?;
+    let task = store.create(task)?;
+    tray::refresh_badge(&app);
+    Ok(task)
+}
+
+/// Replaces a task's editable fields; completion is changed with `toggle_complete`
+#[tauri::command]
+pub fn update_task(
+    app: AppHandle,
+    store: State<'_, TaskStore>,
+    lock: State<'_, AppLock>,
+    id: String,
+    task: TaskInput,
+) -> Result<Task, String> {
+    lock.ensure_unlocked()?;
+    let task = store.update(&id, task)?;
+    tray::refresh_badge(&app);
+    Ok(task)
+}
+
+#[tauri::command]
+pub fn delete_task(
+    app: AppHandle,
+    store: State<'_, TaskStore>,
+    lock: State<'_, AppLock>,
+    id: String,
+) -> Result<(), String> {
+    lock.ensure_unlocked()?;
+    store.delete(&id)?;
+    tray::refresh_badge(&app);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn toggle_complete(
+    app: AppHandle,
+    store: State<'_, TaskStore>,
+    lock: State<'_, AppLock>,
+    id: String,
+) -> Result<Task, String> {
+    lock.ensure_unlocked()?;
+    let task = store.toggle_complete(&id)?;
+    tray::refresh_badge(&app);
+    let message = if task.completed { "Task completed" } else { "Task marked as not completed" };
+    accessibility::announce(&app, message);
+    Ok(task)
+}
+
+/// Local changes to push for the signed-in user; tasks from a previous account are dropped
+#[tauri::command]
+pub fn pending_task_changes(
+    app: AppHandle,
+    store: State<'_, TaskStore>,
+    lock: State<'_, AppLock>,
+    user_id: String,
+) -> Result<Vec<PendingChange>, String> {
+    lock.ensure_unlocked()?;
+    let changes = store.pending_changes(&user_id)?;
+    tray::refresh_badge(&app);
+    Ok(changes)
+}
+
+#[tauri::command]
+pub fn mark_tasks_synced(
+    store: State<'_, TaskStore>,
+    lock: State<'_, AppLock>,
+    changes: Vec<SyncedChange>,
+) -> Result<(), String> {
+    lock.ensure_unlocked()?;
+    store.mark_synced(&changes)
+}
+
+/// Merges the signed-in user's Supabase tasks into the local store
+#[tauri::command]
+pub fn merge_remote_tasks(
+    app: AppHandle,
+    store: State<'_, TaskStore>,
+    lock: State<'_, AppLock>,
+    user_id: String,
+    tasks: Vec<RemoteTask>,
+) -> Result<(), String> {
+    lock.ensure_unlocked()?;
+    let imported = store.merge_remote(&user_id, tasks)?;
+    tray::refresh_badge(&app);
+    match imported {
+        0 => {}
+        1 => accessibility::announce(&app, "1 task imported"),
+        n => accessibility::announce(&app, &format!("{} tasks imported", n)),
+    }
+    Ok(())
+}
+
+/// Clears the local tasks when the user signs out. Removing data reveals
+/// nothing, so this works while the app is locked.
+#[tauri::command]
+pub fn clear_local_tasks(app: AppHandle, store: State<'_, TaskStore>) -> Result<(), String> {
+    store.clear()?;
+    tray::refresh_badge(&app);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(title: &str) -> TaskInput {
+        TaskInput::new(title.to_string(), None)
+    }
+
+    fn migrated() -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.pragma_update(None, "foreign_keys", true).unwrap();
+        migrate(&mut conn).unwrap();
+        conn
+    }
+
+    fn nine() -> NaiveTime {
+        NaiveTime::from_hms_opt(9, 0, 0).unwrap()
+    }
+
+    fn store() -> TaskStore {
+        TaskStore {
+            conn: Mutex::new(Some(migrated())),
+            reminder_time: Mutex::new(nine()),
+        }
+    }
+
+    #[test]
+    fn validate_trims_and_defaults() {
+        let fields = TaskInput {
+            notes: Some("  ".to_string()),
+            due_date: Some(String::new()),
+            ..input("  Buy milk  ")
+        }
+        .validate()
+        .unwrap();
+        assert_eq!(fields.title, "Buy milk");
+        assert_eq!(fields.notes, None);
+        assert_eq!(fields.due_date, None);
+        assert_eq!(fields.tags, "[]");
+    }
+
+    #[test]
+    fn validate_rejects_bad_titles() {
+        assert!(input("   ").validate().is_err());
+        assert!(input(&"x".repeat(MAX_TITLE_LEN)).validate().is_ok());
+        assert!(input(&"x".repeat(MAX_TITLE_LEN + 1)).validate().is_err());
+    }
+
+    #[test]
+    fn validate_checks_due_date_format() {
+        let due = |date: &str| TaskInput::new("Task".to_string(), Some(date.to_string())).validate();
+        assert_eq!(due("2026-02-28").unwrap().due_date.as_deref(), Some("2026-02-28"));
+        assert!(due("2026-02-29").is_err());
+        assert!(due("28/02/2026").is_err());
+    }
+
+    #[test]
+    fn validate_dedupes_and_limits_tags() {
+        let tags = |tags: Vec<String>| TaskInput { tags, ..input("Task") }.validate();
+        let fields = tags(vec!["work".into(), " work ".into(), "".into(), "home".into()]).unwrap();
+        assert_eq!(fields.tags, r#"["work","home"]"#);
+
+        assert!(tags(vec!["x".repeat(MAX_TAG_LEN + 1)]).is_err());
+        assert!(tags((0..MAX_TAGS).map(|i| i.to_string()).collect()).is_ok());
+        assert!(tags((0..=MAX_TAGS).map(|i| i.to_string()).collect()).is_err());
+    }
+
+    #[test]
+    fn migrate_applies_every_migration_once() {
+        let mut conn = migrated();
+        let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap();
+        assert_eq!(version, MIGRATIONS.len() as i64);
+
+        // Re-running is a no-op rather than failing on existing tables
+        migrate(&mut conn).unwrap();
+        conn.prepare(SELECT_TASK).unwrap();
+    }
+
+    #[test]
+    fn migrate_upgrades_an_existing_database() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(MIGRATIONS[0]).unwrap();
+        conn.pragma_update(None, "user_version", 1).unwrap();
+        conn.execute(
+            "INSERT INTO tasks (id, title, completed, created_at, updated_at) VALUES ('a', 'Done', 1, 1, 5)",
+            [],
+        )
+        .unwrap();
+
+        migrate(&mut conn).unwrap();
+        let task = get(&conn, "a").unwrap();
+        assert!(task.completed);
+        assert_eq!(task.completed_at, Some(5));
+    }
+
+    #[test]
+    fn edit_after_read_stays_pending_within_the_same_second() {
+        let store = store();
+        let task = store.create(input("Task")).unwrap();
+        store.toggle_complete(&task.id).unwrap();
+        let pushed = store.pending_changes("user").unwrap().remove(0);
+
+        // Undo right after completing, before the push is confirmed
+        store.toggle_complete(&task.id).unwrap();
+        store
+            .mark_synced(&[SyncedChange {
+                id: pushed.id,
+                revision: pushed.revision,
+            }])
+            .unwrap();
+
+        let pending = store.pending_changes("user").unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].completed_at, None);
+    }
+
+    #[test]
+    fn merge_keeps_unpushed_local_changes() {
+        let conn = migrated();
+        conn.execute(
+            "INSERT INTO tasks (id, title, created_at, updated_at, dirty) VALUES ('a', 'Local edit', 1, 1, 1)",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO tasks (id, title, created_at, updated_at, dirty) VALUES ('b', 'Gone remotely', 1, 1, 0)",
+            [],
+        )
+        .unwrap();
+
+        let remote = vec![
+            RemoteTask {
+                id: "a".to_string(),
+                name: "Remote edit".to_string(),
+                created_at: 1,
+                completed_at: None,
+                deleted_at: None,
+            },
+            RemoteTask {
+                id: "c".to_string(),
+                name: "From another device".to_string(),
+                created_at: 1,
+                completed_at: Some(2),
+                deleted_at: Some(2),
+            },
+        ];
+        assert_eq!(merge_remote(&conn, "user", remote, nine()).unwrap(), 1);
+
+        assert_eq!(get(&conn, "a").unwrap().title, "Local edit");
+        assert!(get(&conn, "b").is_err());
+        let imported = get(&conn, "c").unwrap();
+        assert!(imported.completed);
+        assert!(imported.due_date.is_some());
+    }
+
+    #[test]
+    fn merge_for_another_account_clears_local_tasks() {
+        let conn = migrated();
+        merge_remote(&conn, "first", Vec::new(), nine()).unwrap();
+        conn.execute(
+            "INSERT INTO tasks (id, title, created_at, updated_at) VALUES ('a', 'Not theirs', 1, 1)",
+            [],
+        )
+        .unwrap();
+
+        merge_remote(&conn, "second", Vec::new(), nine()).unwrap();
+        assert!(get(&conn, "a").is_err());
+    }
+
+    #[test]
+    fn claim_drops_another_accounts_unpushed_changes() {
+        let conn = migrated();
+        claim(&conn, "first").unwrap();
+        conn.execute(
+            "INSERT INTO tasks (id, title, created_at, updated_at, dirty) VALUES ('a', 'Unpushed', 1, 1, 1)",
+            [],
+        )
+        .unwrap();
+
+        claim(&conn, "first").unwrap();
+        assert!(get(&conn, "a").is_ok());
+
+        claim(&conn, "second").unwrap();
+        assert!(get(&conn, "a").is_err());
+        assert_eq!(owner(&conn).unwrap().as_deref(), Some("second"));
+    }
+}
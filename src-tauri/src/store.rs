@@ -0,0 +1,75 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+// Set once all app data has been erased; settings are never written again until restart
+static WRITES_BLOCKED: AtomicBool = AtomicBool::new(false);
+
+/// Resolves a settings file inside the app config directory
+pub fn config_path(app: &AppHandle, file_name: &str) -> Result<PathBuf, String> {
+    app.path()
+        .app_config_dir()
+        .map(|dir| dir.join(file_name))
+        .map_err(|e| e.to_string())
+}
+
+/// Reads a JSON settings file, falling back to defaults if it is missing or unreadable
+pub fn read_json<T: DeserializeOwned + Default>(path: &Path) -> T {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|_e| {
+            #[cfg(debug_assertions)]
+            eprintln!("Failed to parse {}: {:?}", path.display(), _e);
+            T::default()
+        }),
+        Err(_) => T::default(),
+    }
+}
+
+/// Stops every later `write_json` call, so nothing recreates the config
+/// directory after an erase
+pub fn block_writes() {
+    WRITES_BLOCKED.store(true, Ordering::SeqCst);
+}
+
+/// Writes a JSON settings file atomically (write to a temp file, then rename)
+pub fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    if WRITES_BLOCKED.load(Ordering::SeqCst) {
+        return Err("App data has been erased; restart the app".to_string());
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let contents = serde_json::to_string(value).map_err(|e| e.to_string())?;
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, contents).map_err(|e| e.to_string())?;
+    fs::rename(&tmp, path).map_err(|e| e.to_string())
+}
+
+/// Current Unix time in seconds
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Emits an event to the main window only
+pub fn emit_to_main<S: Serialize + Clone>(app: &AppHandle, event: &str, payload: S) {
+    if let Some(window) = app.get_webview_window("main") {
+        // Validate window label before emitting
+        if window.label() != "main" {
+            #[cfg(debug_assertions)]
+            println!("Event rejected: window label is not 'main'");
+            return;
+        }
+        window.emit(event, payload).unwrap_or_else(|_e| {
+            #[cfg(debug_assertions)]
+            eprintln!("Failed to emit {} event: {:?}", event, _e);
+        });
+    }
+}
@@ -0,0 +1,64 @@
+use chrono::Local;
+use tauri::menu::{MenuBuilder, MenuItemBuilder};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Manager};
+
+use crate::app_lock::AppLock;
+use crate::storage::TaskStore;
+
+const TRAY_ID: &str = "main-tray";
+
+/// Builds the tray icon and its menu (Quick Add Task / Show App / Quit).
+/// Menu clicks are handled with the app menu in `platform::handle_menu_event`.
+pub fn setup(app: &AppHandle) -> tauri::Result<()> {
+    let quick_add_item = MenuItemBuilder::with_id("quick_add", "Quick Add Task").build(app)?;
+    let show_app = MenuItemBuilder::with_id("show_app", "Show App").build(app)?;
+    let quit = MenuItemBuilder::with_id("quit", "Quit").build(app)?;
+    let menu = MenuBuilder::new(app)
+        .item(&quick_add_item)
+        .item(&show_app)
+        .separator()
+        .item(&quit)
+        .build()?;
+
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID).tooltip("Todo App").menu(&menu);
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    builder.build(app)?;
+
+    refresh_badge(app);
+    Ok(())
+}
+
+/// Updates the tray badge with the number of incomplete tasks due today or earlier.
+/// Overdue tasks stay in the Today list (marked late), so they are counted too.
+pub fn refresh_badge(app: &AppHandle) {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return;
+    };
+
+    // The count alone says little, but stay consistent with the lock screen
+    let count = if app.state::<AppLock>().is_locked() {
+        0
+    } else {
+        let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+        app.state::<TaskStore>().count_due(&today).unwrap_or_else(|_e| {
+            #[cfg(debug_assertions)]
+            eprintln!("Failed to count due tasks: {}", _e);
+            0
+        })
+    };
+
+    let title = (count > 0).then(|| count.to_string());
+    let tooltip = match count {
+        0 => "Todo App".to_string(),
+        1 => "Todo App: 1 task due today or overdue".to_string(),
+        n => format!("Todo App: {} tasks due today or overdue", n),
+    };
+    let result = tray.set_title(title).and_then(|_| tray.set_tooltip(Some(tooltip)));
+    if let Err(_e) = result {
+        #[cfg(debug_assertions)]
+        eprintln!("Failed to update tray badge: {:?}", _e);
+    }
+}
@@ -0,0 +1,264 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_updater::{Update, UpdaterExt};
+
+use crate::accessibility;
+use crate::store::{self, emit_to_main, now_secs};
+
+const UPDATE_FILE: &str = "update_policy.json";
+
+// Delay before the first check after launch, then how often to check again
+const INITIAL_CHECK_DELAY: Duration = Duration::from_secs(30);
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+// "Remind me tomorrow" snoozes update prompts for a day
+const DEFER_SECS: u64 = 24 * 60 * 60;
+
+/// How an available update is delivered
+#[derive(Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UpdatePolicy {
+    /// Ask as soon as an update is found (previous behavior)
+    #[default]
+    Prompt,
+    /// Download silently, then show a single restart prompt
+    Background,
+    /// Download silently and install when the app quits, without prompting
+    InstallOnQuit,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct UpdateSettings {
+    policy: UpdatePolicy,
+    remind_after: Option<u64>,
+}
+
+/// A downloaded update waiting to be installed
+struct PendingUpdate {
+    update: Update,
+    bytes: Vec<u8>,
+}
+
+struct UpdateInner {
+    settings: UpdateSettings,
+    pending: Option<PendingUpdate>,
+    /// Version of the prompt the user was last shown and hasn't answered with "later"
+    prompted_version: Option<String>,
+}
+
+pub struct UpdateManager {
+    path: PathBuf,
+    inner: Mutex<UpdateInner>,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateNotice {
+    version: String,
+    notes: String,
+}
+
+impl UpdateNotice {
+    fn from_update(update: &Update) -> Self {
+        Self {
+            version: update.version.clone(),
+            notes: update.body.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl UpdateInner {
+    /// Asks the main window to prompt for an update, unless it already did for
+    /// this version. Returns whether a prompt was sent.
+    fn prompt(&mut self, app: &AppHandle, event: &str, notice: UpdateNotice) -> bool {
+        if self.prompted_version.as_deref() == Some(notice.version.as_str()) {
+            return false;
+        }
+        self.prompted_version = Some(notice.version.clone());
+        emit_to_main(app, event, notice);
+        true
+    }
+}
+
+impl UpdateManager {
+    pub fn load(app: &AppHandle) -> Result<Self, String> {
+        let path = store::config_path(app, UPDATE_FILE)?;
+        let settings: UpdateSettings = store::read_json(&path);
+        Ok(Self {
+            path,
+            inner: Mutex::new(UpdateInner {
+                settings,
+                pending: None,
+                prompted_version: None,
+            }),
+        })
+    }
+
+    fn is_deferred(inner: &UpdateInner) -> bool {
+        inner
+            .settings
+            .remind_after
+            .is_some_and(|until| now_secs() < until)
+    }
+}
+
+/// Checks for an update and delivers it according to the saved policy
+async fn check_once(app: &AppHandle) -> Result<(), String> {
+    let manager = app.state::<UpdateManager>();
+
+    // Already downloaded: only re-surface the restart prompt once a deferral ends
+    {
+        let mut inner = manager.inner.lock().unwrap();
+        if let Some(notice) = inner.pending.as_ref().map(|pending| UpdateNotice::from_update(&pending.update)) {
+            if inner.settings.policy == UpdatePolicy::Background && !UpdateManager::is_deferred(&inner) {
+                inner.prompt(app, "update-ready", notice);
+            }
+            return Ok(());
+        }
+    }
+
+    let Some(update) = app
+        .updater()
+        .map_err(|e| e.to_string())?
+        .check()
+        .await
+        .map_err(|e| e.to_string())?
+    else {
+        return Ok(());
+    };
+
+    let policy = manager.inner.lock().unwrap().settings.policy;
+    if policy == UpdatePolicy::Prompt {
+        let mut inner = manager.inner.lock().unwrap();
+        if !UpdateManager::is_deferred(&inner) {
+            inner.prompt(app, "update-available", UpdateNotice::from_update(&update));
+        }
+        return Ok(());
+    }
+
+    let bytes = update
+        .download(|_chunk, _total| {}, || {})
+        .await
+        .map_err(|e| e.to_string())?;
+    #[cfg(debug_assertions)]
+    println!("Downloaded update {} in the background", update.version);
+
+    let mut inner = manager.inner.lock().unwrap();
+    if policy == UpdatePolicy::Background
+        && !UpdateManager::is_deferred(&inner)
+        && inner.prompt(app, "update-ready", UpdateNotice::from_update(&update))
+    {
+        accessibility::announce(app, "An update has been downloaded and is ready to install");
+    }
+    inner.pending = Some(PendingUpdate { update, bytes });
+    Ok(())
+}
+
+/// Spawns the periodic update checker
+pub fn spawn_checker(app: AppHandle) {
+    thread::spawn(move || {
+        thread::sleep(INITIAL_CHECK_DELAY);
+        loop {
+            if let Err(_e) = tauri::async_runtime::block_on(check_once(&app)) {
+                #[cfg(debug_assertions)]
+                eprintln!("Update check failed: {}", _e);
+            }
+            thread::sleep(CHECK_INTERVAL);
+        }
+    });
+}
+
+/// Installs a downloaded update while the app is exiting, if the policy is "install on quit".
+/// Under the other policies the update waits for the user to accept the prompt.
+pub fn install_on_exit(app: &AppHandle) {
+    let manager = app.state::<UpdateManager>();
+    let pending = {
+        let mut inner = manager.inner.lock().unwrap();
+        if inner.settings.policy != UpdatePolicy::InstallOnQuit {
+            return;
+        }
+        inner.pending.take()
+    };
+    let Some(pending) = pending else {
+        return;
+    };
+    if let Err(_e) = pending.update.install(&pending.bytes) {
+        #[cfg(debug_assertions)]
+        eprintln!("Failed to install update on exit: {:?}", _e);
+    }
+}
+
+#[tauri::command]
+pub fn get_update_policy(manager: State<'_, UpdateManager>) -> UpdatePolicy {
+    manager.inner.lock().unwrap().settings.policy
+}
+
+#[tauri::command]
+pub fn set_update_policy(
+    manager: State<'_, UpdateManager>,
+    policy: UpdatePolicy,
+) -> Result<UpdatePolicy, String> {
+    let mut inner = manager.inner.lock().unwrap();
+    inner.settings.policy = policy;
+    store::write_json(&manager.path, &inner.settings)?;
+    Ok(policy)
+}
+
+/// "Remind me tomorrow": suppresses update prompts for a day
+#[tauri::command]
+pub fn defer_update(manager: State<'_, UpdateManager>) -> Result<(), String> {
+    let mut inner = manager.inner.lock().unwrap();
+    inner.settings.remind_after = Some(now_secs() + DEFER_SECS);
+    // Prompt again once the deferral ends
+    inner.prompted_version = None;
+    store::write_json(&manager.path, &inner.settings)
+}
+
+/// Installs the update now (using the background download if there is one) and restarts
+#[tauri::command]
+pub async fn install_update_now(app: AppHandle, manager: State<'_, UpdateManager>) -> Result<(), String> {
+    let pending = manager.inner.lock().unwrap().pending.take();
+    match pending {
+        Some(pending) => {
+            if let Err(e) = pending.update.install(&pending.bytes) {
+                // Keep the download so the user can retry without waiting for the next check
+                let mut inner = manager.inner.lock().unwrap();
+                inner.pending = Some(pending);
+                inner.prompted_version = None;
+                return Err(e.to_string());
+            }
+        }
+        None => {
+            let Some(update) = app
+                .updater()
+                .map_err(|e| e.to_string())?
+                .check()
+                .await
+                .map_err(|e| e.to_string())?
+            else {
+                return Err("No update available".to_string());
+            };
+            update
+                .download_and_install(|_chunk, _total| {}, || {})
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+    }
+
+    // The update is installed; failing to clear the deferral must not block the restart
+    {
+        let mut inner = manager.inner.lock().unwrap();
+        inner.settings.remind_after = None;
+        if let Err(_e) = store::write_json(&manager.path, &inner.settings) {
+            #[cfg(debug_assertions)]
+            eprintln!("Failed to save update settings: {}", _e);
+        }
+    }
+    app.restart()
+}
@@ -0,0 +1,227 @@
+use chrono::{Datelike, Days, Local, NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use crate::storage::TaskStore;
+use crate::store;
+
+const WORK_HOURS_FILE: &str = "work_hours.json";
+
+// Upper bound for "due in X business days" (about ten years)
+const MAX_BUSINESS_DAYS: u32 = 2600;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const TIME_FORMAT: &str = "%H:%M";
+
+/// The user's working week, respected by business-day calculations
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WorkSchedule {
+    /// ISO weekday numbers, 1 = Monday through 7 = Sunday
+    work_days: Vec<u8>,
+    /// Local start and end of the working day as "HH:MM"
+    start_time: String,
+    end_time: String,
+}
+
+impl Default for WorkSchedule {
+    fn default() -> Self {
+        Self {
+            work_days: vec![1, 2, 3, 4, 5],
+            start_time: "09:00".to_string(),
+            end_time: "17:00".to_string(),
+        }
+    }
+}
+
+impl WorkSchedule {
+    fn validate(&self) -> Result<(), String> {
+        if self.work_days.is_empty() || self.work_days.iter().any(|day| !(1..=7).contains(day)) {
+            return Err("Work days must be between 1 (Monday) and 7 (Sunday), with at least one day".to_string());
+        }
+        let start = parse_time(&self.start_time)?;
+        let end = parse_time(&self.end_time)?;
+        if start >= end {
+            return Err("Work hours must end after they start".to_string());
+        }
+        Ok(())
+    }
+
+    /// Start of the working day; due-date reminders fire at this time
+    pub fn start_time(&self) -> NaiveTime {
+        parse_time(&self.start_time).unwrap_or_else(|_| default_start_time())
+    }
+
+    /// Whether `at` (local time) falls within working hours on a work day
+    pub fn is_working_time(&self, at: NaiveDateTime) -> bool {
+        let (Ok(start), Ok(end)) = (parse_time(&self.start_time), parse_time(&self.end_time)) else {
+            return true;
+        };
+        self.is_work_day(at.date()) && (start..end).contains(&at.time())
+    }
+
+    fn is_work_day(&self, date: NaiveDate) -> bool {
+        let weekday = date.weekday().number_from_monday() as u8;
+        self.work_days.contains(&weekday)
+    }
+
+    /// First work day strictly after `date`
+    pub fn next_business_day(&self, date: NaiveDate) -> Option<NaiveDate> {
+        // A validated schedule has at least one work day, so a week always contains one
+        (1..=7)
+            .filter_map(|offset| date.checked_add_days(Days::new(offset)))
+            .find(|candidate| self.is_work_day(*candidate))
+    }
+
+    /// The date `days` work days after `date`
+    pub fn add_business_days(&self, date: NaiveDate, days: u32) -> Option<NaiveDate> {
+        (0..days).try_fold(date, |current, _| self.next_business_day(current))
+    }
+}
+
+fn default_start_time() -> NaiveTime {
+    NaiveTime::from_hms_opt(9, 0, 0).unwrap_or_default()
+}
+
+fn parse_time(value: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(value, TIME_FORMAT).map_err(|_| format!("Invalid time: {}", value))
+}
+
+fn parse_date(value: Option<String>) -> Result<NaiveDate, String> {
+    match value {
+        Some(value) => NaiveDate::parse_from_str(&value, DATE_FORMAT)
+            .map_err(|_| format!("Invalid date: {}", value)),
+        None => Ok(Local::now().date_naive()),
+    }
+}
+
+/// Loads the saved schedule, falling back to Monday-Friday 9-5 if it is invalid
+pub fn load_schedule(app: &AppHandle) -> Result<WorkSchedule, String> {
+    let schedule: WorkSchedule = store::read_json(&store::config_path(app, WORK_HOURS_FILE)?);
+    Ok(if schedule.validate().is_ok() {
+        schedule
+    } else {
+        WorkSchedule::default()
+    })
+}
+
+#[tauri::command]
+pub fn get_work_schedule(app: AppHandle) -> Result<WorkSchedule, String> {
+    load_schedule(&app)
+}
+
+#[tauri::command]
+pub fn set_work_schedule(app: AppHandle, mut schedule: WorkSchedule) -> Result<WorkSchedule, String> {
+    schedule.validate()?;
+    schedule.work_days.sort_unstable();
+    schedule.work_days.dedup();
+    store::write_json(&store::config_path(&app, WORK_HOURS_FILE)?, &schedule)?;
+    app.state::<TaskStore>().set_reminder_time(schedule.start_time())?;
+    Ok(schedule)
+}
+
+/// Next work day after `from` (YYYY-MM-DD, defaults to today)
+#[tauri::command]
+pub fn next_business_day(app: AppHandle, from: Option<String>) -> Result<String, String> {
+    let date = parse_date(from)?;
+    load_schedule(&app)?
+        .next_business_day(date)
+        .map(|day| day.format(DATE_FORMAT).to_string())
+        .ok_or_else(|| "Date out of range".to_string())
+}
+
+/// Date that is `days` business days after `from` (YYYY-MM-DD, defaults to today)
+#[tauri::command]
+pub fn add_business_days(app: AppHandle, from: Option<String>, days: u32) -> Result<String, String> {
+    if days > MAX_BUSINESS_DAYS {
+        return Err(format!("Cannot add more than {} business days", MAX_BUSINESS_DAYS));
+    }
+    let date = parse_date(from)?;
+    load_schedule(&app)?
+        .add_business_days(date, days)
+        .map(|day| day.format(DATE_FORMAT).to_string())
+        .ok_or_else(|| "Date out of range".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, DATE_FORMAT).unwrap()
+    }
+
+    fn schedule(work_days: &[u8]) -> WorkSchedule {
+        WorkSchedule {
+            work_days: work_days.to_vec(),
+            ..WorkSchedule::default()
+        }
+    }
+
+    #[test]
+    fn next_business_day_skips_the_weekend() {
+        let week = WorkSchedule::default();
+        // 2026-10-16 is a Friday
+        assert_eq!(week.next_business_day(date("2026-10-15")), Some(date("2026-10-16")));
+        assert_eq!(week.next_business_day(date("2026-10-16")), Some(date("2026-10-19")));
+        assert_eq!(week.next_business_day(date("2026-10-17")), Some(date("2026-10-19")));
+    }
+
+    #[test]
+    fn next_business_day_follows_custom_work_days() {
+        // Sunday to Thursday
+        let week = schedule(&[7, 1, 2, 3, 4]);
+        assert_eq!(week.next_business_day(date("2026-10-15")), Some(date("2026-10-18")));
+        // A single work day is a week away
+        assert_eq!(schedule(&[3]).next_business_day(date("2026-10-14")), Some(date("2026-10-21")));
+    }
+
+    #[test]
+    fn add_business_days_counts_work_days_only() {
+        let week = WorkSchedule::default();
+        assert_eq!(week.add_business_days(date("2026-10-14"), 0), Some(date("2026-10-14")));
+        assert_eq!(week.add_business_days(date("2026-10-14"), 5), Some(date("2026-10-21")));
+        // Starting on a Saturday, the first business day is Monday
+        assert_eq!(week.add_business_days(date("2026-10-17"), 1), Some(date("2026-10-19")));
+        assert_eq!(week.add_business_days(date("2026-12-31"), 2), Some(date("2027-01-04")));
+    }
+
+    #[test]
+    fn add_business_days_stops_at_the_end_of_the_calendar() {
+        let week = schedule(&[1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(week.add_business_days(NaiveDate::MAX, 1), None);
+        assert_eq!(week.next_business_day(NaiveDate::MAX), None);
+    }
+
+    #[test]
+    fn working_time_covers_work_days_between_start_and_end() {
+        let week = WorkSchedule::default();
+        let at = |value: &str| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap();
+        assert!(week.is_working_time(at("2026-10-16 09:00")));
+        assert!(week.is_working_time(at("2026-10-16 16:59")));
+        assert!(!week.is_working_time(at("2026-10-16 17:00")));
+        assert!(!week.is_working_time(at("2026-10-16 08:59")));
+        // Saturday
+        assert!(!week.is_working_time(at("2026-10-17 10:00")));
+    }
+
+    #[test]
+    fn validate_rejects_bad_schedules() {
+        assert!(WorkSchedule::default().validate().is_ok());
+        assert!(schedule(&[]).validate().is_err());
+        assert!(schedule(&[0, 1]).validate().is_err());
+        assert!(schedule(&[8]).validate().is_err());
+
+        let backwards = WorkSchedule {
+            start_time: "17:00".to_string(),
+            end_time: "09:00".to_string(),
+            ..WorkSchedule::default()
+        };
+        assert!(backwards.validate().is_err());
+        let malformed = WorkSchedule {
+            start_time: "9am".to_string(),
+            ..WorkSchedule::default()
+        };
+        assert!(malformed.validate().is_err());
+    }
+}
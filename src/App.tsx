@@ -1,28 +1,65 @@
 import React, { useState, useEffect, useCallback, useRef } from 'react';
 import { listen } from '@tauri-apps/api/event';
+import { invoke } from '@tauri-apps/api/core';
 import { supabase } from './lib/supabase';
+import { createTask, deleteTask, listTasks, localDateKey, toggleComplete, type LocalTask } from './lib/tasks';
+import { syncTasks } from './lib/taskSync';
 import { useAuth } from './contexts/AuthContext';
 import Preferences from './components/Preferences';
 import { logger } from './lib/logger';
+import { isValidEvent } from './lib/events';
 import './styles.css';
 
-// Allowed event names for IPC validation
-const ALLOWED_EVENTS = ['sign-out-user', 'navigate-to-preferences'] as const;
-
-// Validates that an event name is in the allowlist
-const isValidEvent = (eventName: string): boolean => {
-  return ALLOWED_EVENTS.includes(eventName as any);
-};
+// Shortcuts accept both Cmd and Ctrl; show the one this platform uses
+const MOD_KEY = navigator.userAgent.includes('Mac') ? '⌘' : 'Ctrl';
 
 interface Task {
   id: string;
   name: string;
   createdAt: number;
   completedAt?: number;
-  deletedAt?: number;
+  /** YYYY-MM-DD */
+  dueDate: string | null;
   isLate?: boolean;
 }
 
+// Short label for a future due date, e.g. "Due Oct 16"
+const formatDueDate = (dueDate: string) => {
+  const [year, month, day] = dueDate.split('-').map(Number);
+  const date = new Date(year, month - 1, day);
+  return `Due ${date.toLocaleDateString('en-US', { month: 'short', day: 'numeric' })}`;
+};
+
+// Tasks still open after their due date are late
+const toTask = (task: LocalTask): Task => ({
+  id: task.id,
+  name: task.title,
+  createdAt: task.createdAt * 1000,
+  completedAt: task.completedAt !== null ? task.completedAt * 1000 : undefined,
+  dueDate: task.dueDate,
+  isLate: !task.completed && task.dueDate !== null && task.dueDate < localDateKey(),
+});
+
+// Hint under the due date picker. Due-date reminders fire at the start of the
+// working day (`startTime`, "HH:MM"); none is set once that time has passed.
+const reminderHint = (dueDate: string, startTime: string) => {
+  const [year, month, day] = dueDate.split('-').map(Number);
+  const [hours, minutes] = startTime.split(':').map(Number);
+  const fireAt = new Date(year, month - 1, day, hours, minutes);
+  const time = fireAt.toLocaleTimeString('en-US', { hour: 'numeric', minute: '2-digit' });
+  return fireAt.getTime() > Date.now()
+    ? `Reminds you at ${time} that day`
+    : `No reminder: ${time} has already passed`;
+};
+
+interface ReminderFiredEvent {
+  reminderId: string;
+  taskId: string;
+}
+
+// How long a task stays highlighted after its reminder brings the app forward
+const REMINDER_HIGHLIGHT_MS = 5000;
+
 interface CompletedTaskHistory {
   task: Task;
   completedAt: number;
@@ -41,10 +78,14 @@ const App: React.FC = () => {
   const { user, signOut } = useAuth();
   const [tasks, setTasks] = useState<Task[]>([]);
   const [newTaskName, setNewTaskName] = useState('');
+  // Due dates get a reminder at the start of the working day from the backend scheduler
+  const [newTaskDue, setNewTaskDue] = useState(localDateKey);
   const [showInput, setShowInput] = useState(false);
   const [inputRef, setInputRef] = useState<HTMLInputElement | null>(null);
   const [history, setHistory] = useState<CompletedTaskHistory[]>([]);
   const [showPreferences, setShowPreferences] = useState(false);
+  const [highlightedTaskId, setHighlightedTaskId] = useState<string | null>(null);
+  const [workStartTime, setWorkStartTime] = useState('09:00');
   const maxHistorySize = 10;
   
   // Rate limiting for IPC events
@@ -66,48 +107,26 @@ const App: React.FC = () => {
 
   const loadTasks = useCallback(async () => {
     if (!user) return;
-    
+
     try {
-      const { data, error } = await supabase
-        .from('tasks')
-        .select('*')
-        .eq('user_id', user.id)
-        .order('created_at', { ascending: true });
-
-      if (error) throw error;
-
-      if (data) {
-        const mappedTasks: Task[] = data.map(task => ({
-          id: task.id,
-          name: task.name,
-          createdAt: new Date(task.created_at).getTime(),
-          completedAt: task.completed_at ? new Date(task.completed_at).getTime() : undefined,
-          deletedAt: task.deleted_at ? new Date(task.deleted_at).getTime() : undefined,
-          isLate: task.is_late || false,
-        }));
-        setTasks(mappedTasks);
-      }
+      const localTasks = await listTasks();
+      setTasks(localTasks.map(toTask));
     } catch (error) {
       logger.error(error, { context: 'load_tasks' });
       setTasks([]);
     }
   }, [user]);
 
-  const runCleanup = useCallback(async () => {
+  // Tasks live in the local store; Supabase is kept in sync in the background
+  const syncAndReload = useCallback(async () => {
     if (!user) return;
-    
+
     try {
-      // Call the cleanup function on the database
-      await supabase.rpc('cleanup_tasks');
-      
-      // Call mark_tasks_late function
-      await supabase.rpc('mark_tasks_late');
-      
-      // Reload tasks to reflect changes
-      await loadTasks();
+      await syncTasks(user.id);
     } catch (error) {
-      logger.error(error, { context: 'cleanup_tasks' });
+      logger.error(error, { context: 'sync_tasks' });
     }
+    await loadTasks();
   }, [user, loadTasks]);
 
   useEffect(() => {
@@ -148,11 +167,127 @@ const App: React.FC = () => {
   }, [user]);
 
   useEffect(() => {
-    // Run cleanup on mount and every hour
-    runCleanup();
-    const interval = setInterval(runCleanup, 60 * 60 * 1000);
+    // Sync on mount and every hour
+    syncAndReload();
+    const interval = setInterval(syncAndReload, 60 * 60 * 1000);
     return () => clearInterval(interval);
-  }, [runCleanup]);
+  }, [syncAndReload]);
+
+  useEffect(() => {
+    // Re-sync after the machine wakes or the local date rolls over so the
+    // "Today" view and late flags never show yesterday's state
+    const unlisteners: Array<() => void> = [];
+    let cancelled = false;
+
+    (async () => {
+      try {
+        for (const eventName of ['system-resumed', 'day-changed'] as const) {
+          const unlisten = await listen(eventName, () => {
+            if (!isValidEvent(eventName)) {
+              logger.warn('Invalid event name rejected', { event: eventName });
+              return;
+            }
+            logger.debug('Lifecycle event received', { event: eventName });
+            syncAndReload();
+          });
+          if (cancelled) {
+            unlisten();
+          } else {
+            unlisteners.push(unlisten);
+          }
+        }
+      } catch (error) {
+        logger.error(error, { context: 'setup_lifecycle_listeners' });
+      }
+    })();
+
+    return () => {
+      cancelled = true;
+      unlisteners.forEach(unlisten => unlisten());
+    };
+  }, [syncAndReload]);
+
+  useEffect(() => {
+    // Tasks saved from the quick-add window go straight to the local store
+    let unlisten: (() => void) | null = null;
+    let cancelled = false;
+
+    listen('tasks-changed', () => {
+      if (!isValidEvent('tasks-changed')) {
+        logger.warn('Invalid event name rejected', { event: 'tasks-changed' });
+        return;
+      }
+      syncAndReload();
+    })
+      .then(fn => {
+        if (cancelled) fn();
+        else unlisten = fn;
+      })
+      .catch(error => logger.error(error, { context: 'setup_tasks_listener' }));
+
+    return () => {
+      cancelled = true;
+      unlisten?.();
+    };
+  }, [syncAndReload]);
+
+  useEffect(() => {
+    // The work schedule can change in Preferences, so re-read it when they close
+    if (showPreferences) return;
+    invoke<{ startTime: string }>('get_work_schedule')
+      .then(schedule => setWorkStartTime(schedule.startTime))
+      .catch(error => logger.error(error, { context: 'load_work_schedule' }));
+  }, [showPreferences]);
+
+  useEffect(() => {
+    // Notifications can't report clicks on desktop. Clicking one brings the
+    // app forward, so the latest reminder's task is selected on the next
+    // activation, or right away if the app is already in front.
+    let unlisten: (() => void) | null = null;
+    let cancelled = false;
+    let pendingTaskId: string | null = null;
+    let timeout: ReturnType<typeof setTimeout> | undefined;
+
+    const highlight = (taskId: string) => {
+      setHighlightedTaskId(taskId);
+      document
+        .querySelector(`[data-task-id="${CSS.escape(taskId)}"]`)
+        ?.scrollIntoView({ behavior: 'smooth', block: 'center' });
+      clearTimeout(timeout);
+      timeout = setTimeout(() => setHighlightedTaskId(null), REMINDER_HIGHLIGHT_MS);
+    };
+
+    const handleFocus = () => {
+      if (pendingTaskId === null) return;
+      highlight(pendingTaskId);
+      pendingTaskId = null;
+    };
+
+    listen<ReminderFiredEvent>('reminder-fired', event => {
+      if (!isValidEvent('reminder-fired') || typeof event.payload?.taskId !== 'string') {
+        logger.warn('Invalid reminder event rejected');
+        return;
+      }
+      if (document.hasFocus()) {
+        highlight(event.payload.taskId);
+      } else {
+        pendingTaskId = event.payload.taskId;
+      }
+    })
+      .then(fn => {
+        if (cancelled) fn();
+        else unlisten = fn;
+      })
+      .catch(error => logger.error(error, { context: 'setup_reminder_listener' }));
+    window.addEventListener('focus', handleFocus);
+
+    return () => {
+      cancelled = true;
+      clearTimeout(timeout);
+      window.removeEventListener('focus', handleFocus);
+      unlisten?.();
+    };
+  }, []);
 
   useEffect(() => {
     // Listen for menu events from macOS menubar with validation
@@ -234,33 +369,20 @@ const App: React.FC = () => {
     if (!user || !newTaskName.trim()) return;
     
     try {
-      const { data, error } = await supabase
-        .from('tasks')
-        .insert({
-          user_id: user.id,
-          name: newTaskName.trim(),
-        })
-        .select()
-        .single();
-
-      if (error) throw error;
-
-      if (data) {
-        const newTask: Task = {
-          id: data.id,
-          name: data.name,
-          createdAt: new Date(data.created_at).getTime(),
-          isLate: data.is_late || false,
-        };
-        setTasks([...tasks, newTask]);
-      }
+      const created = await createTask({
+        title: newTaskName.trim(),
+        dueDate: newTaskDue || null,
+      });
+      setTasks(current => [...current, toTask(created)]);
       
       setNewTaskName('');
+      setNewTaskDue(localDateKey());
       setShowInput(false);
+      syncAndReload();
     } catch (error) {
       logger.error(error, { context: 'add_task' });
     }
-  }, [newTaskName, tasks, user]);
+  }, [newTaskName, newTaskDue, user, syncAndReload]);
 
   const completeTask = useCallback(async (taskId: string) => {
     if (!user) return;
@@ -269,36 +391,18 @@ const App: React.FC = () => {
     if (!task) return;
 
     try {
-      const now = new Date().toISOString();
-      
-      // Update task in database to mark as completed
-      const { error } = await supabase
-        .from('tasks')
-        .update({
-          completed_at: now,
-          deleted_at: now,
-        })
-        .eq('id', taskId)
-        .eq('user_id', user.id);
-
-      if (error) throw error;
-
-      // Add to local history
-      const completedTask = { 
-        ...task, 
-        completedAt: Date.now(),
-        deletedAt: Date.now()
-      };
-      const updatedHistory = [{ task: completedTask, completedAt: Date.now() }, ...history].slice(0, maxHistorySize);
+      const completed = toTask(await toggleComplete(taskId));
+
+      // Add to local history for undo
+      const updatedHistory = [{ task: completed, completedAt: Date.now() }, ...history].slice(0, maxHistorySize);
       setHistory(updatedHistory);
 
-      // Remove from local state
-      const updatedTasks = tasks.filter(t => t.id !== taskId);
-      setTasks(updatedTasks);
+      setTasks(current => current.map(t => (t.id === taskId ? completed : t)));
+      syncAndReload();
     } catch (error) {
       logger.error(error, { context: 'complete_task' });
     }
-  }, [tasks, history, user]);
+  }, [tasks, history, user, syncAndReload]);
 
   const undoLastCompletion = useCallback(async () => {
     if (!user || history.length === 0) return;
@@ -306,34 +410,30 @@ const App: React.FC = () => {
     const lastCompleted = history[0];
     
     try {
-      // Update task in database to restore it
-      const { error } = await supabase
-        .from('tasks')
-        .update({
-          completed_at: null,
-          deleted_at: null,
-          is_late: false,
-        })
-        .eq('id', lastCompleted.task.id)
-        .eq('user_id', user.id);
-
-      if (error) throw error;
-
-      // Update local state
-      const restoredTask = { ...lastCompleted.task };
-      delete restoredTask.completedAt;
-      delete restoredTask.deletedAt;
-      delete restoredTask.isLate;
-
-      const updatedTasks = [...tasks, restoredTask];
-      setTasks(updatedTasks);
+      // Toggling again restores the task
+      const restored = toTask(await toggleComplete(lastCompleted.task.id));
+      setTasks(current => current.map(t => (t.id === restored.id ? restored : t)));
 
       const updatedHistory = history.slice(1);
       setHistory(updatedHistory);
+      syncAndReload();
     } catch (error) {
       logger.error(error, { context: 'undo_completion' });
     }
-  }, [history, tasks, user]);
+  }, [history, user, syncAndReload]);
+
+  const removeTask = useCallback(async (taskId: string) => {
+    if (!user) return;
+
+    try {
+      await deleteTask(taskId);
+      setTasks(current => current.filter(t => t.id !== taskId));
+      setHistory(current => current.filter(h => h.task.id !== taskId));
+      syncAndReload();
+    } catch (error) {
+      logger.error(error, { context: 'delete_task' });
+    }
+  }, [user, syncAndReload]);
 
   useEffect(() => {
     const handleKeyDown = (e: KeyboardEvent) => {
@@ -351,6 +451,7 @@ const App: React.FC = () => {
       if (e.key === 'Escape' && showInput) {
         setShowInput(false);
         setNewTaskName('');
+        setNewTaskDue(localDateKey());
       }
     };
 
@@ -358,13 +459,14 @@ const App: React.FC = () => {
     return () => window.removeEventListener('keydown', handleKeyDown);
   }, [inputRef, showInput, undoLastCompletion]);
 
-  const incompleteTasks = tasks.filter(t => !t.completedAt && !t.deletedAt).length;
+  const openTasks = tasks.filter(t => !t.completedAt);
+  const incompleteTasks = openTasks.length;
   
   const today = new Date();
   today.setHours(0, 0, 0, 0);
   const todayStart = today.getTime();
   
-  const completedToday = history.filter(h => h.completedAt >= todayStart).length;
+  const completedToday = tasks.filter(t => t.completedAt !== undefined && t.completedAt >= todayStart).length;
   const totalTasksToday = incompleteTasks + completedToday;
   const progressPercentage = totalTasksToday > 0 ? (completedToday / totalTasksToday) * 100 : 0;
   
@@ -395,18 +497,19 @@ const App: React.FC = () => {
       </div>
 
       <div className="tasks-container">
-        {tasks.filter(t => !t.completedAt && !t.deletedAt).length === 0 && !showInput ? (
+        {openTasks.length === 0 && !showInput ? (
           <div className="empty-state">
             <div className="empty-icon">📝</div>
             <div className="empty-text">No tasks for today</div>
-            <div className="empty-subtext">Press ⌘ + N to create one</div>
+            <div className="empty-subtext">Press {MOD_KEY} + N to create one</div>
           </div>
         ) : (
           <div className="task-list">
-            {tasks.filter(task => !task.completedAt && !task.deletedAt).map(task => (
+            {openTasks.map(task => (
               <div 
                 key={task.id} 
-                className={`task-item ${task.isLate ? 'late' : ''} ${task.completedAt ? 'completed' : ''}`}
+                data-task-id={task.id}
+                className={`task-item ${task.isLate ? 'late' : ''} ${task.completedAt ? 'completed' : ''} ${task.id === highlightedTaskId ? 'highlighted' : ''}`}
               >
                 <button
                   className="task-checkbox"
@@ -418,7 +521,17 @@ const App: React.FC = () => {
                 <div className="task-content">
                   <div className="task-name">{task.name}</div>
                   {task.isLate && <div className="late-badge">Late</div>}
+                  {task.dueDate && task.dueDate > localDateKey() && (
+                    <div className="due-badge">{formatDueDate(task.dueDate)}</div>
+                  )}
                 </div>
+                <button
+                  className="task-delete"
+                  onClick={() => removeTask(task.id)}
+                  aria-label="Delete task"
+                >
+                  ×
+                </button>
               </div>
             ))}
           </div>
@@ -440,11 +553,23 @@ const App: React.FC = () => {
               className="new-task-input"
               autoFocus
             />
+            <label className="new-task-due">
+              Due
+              <input
+                type="date"
+                value={newTaskDue}
+                onChange={(e) => setNewTaskDue(e.target.value)}
+              />
+              {newTaskDue && (
+                <span className="new-task-due-hint">{reminderHint(newTaskDue, workStartTime)}</span>
+              )}
+            </label>
             <div className="input-actions">
               <button onClick={addTask} className="btn-primary">Add Task</button>
               <button onClick={() => {
                 setShowInput(false);
                 setNewTaskName('');
+                setNewTaskDue(localDateKey());
               }} className="btn-secondary">Cancel</button>
             </div>
           </div>
@@ -453,10 +578,10 @@ const App: React.FC = () => {
 
       <div className="footer">
         <div className="keyboard-hint">
-          <span>⌘ + N</span> New task
+          <span>{MOD_KEY} + N</span> New task
         </div>
         <div className="keyboard-hint">
-          <span>⌘ + Z</span> Undo
+          <span>{MOD_KEY} + Z</span> Undo
         </div>
         </div>
       </div>
@@ -0,0 +1,63 @@
+import { useEffect, useState, type CSSProperties } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { logger } from '../lib/logger';
+import { isValidEvent } from '../lib/events';
+
+interface AnnouncementEvent {
+  message: string;
+  spokenNatively: boolean;
+}
+
+// Visually hidden but still read by screen readers
+const visuallyHidden: CSSProperties = {
+  position: 'absolute',
+  width: '1px',
+  height: '1px',
+  padding: 0,
+  margin: '-1px',
+  overflow: 'hidden',
+  clip: 'rect(0, 0, 0, 0)',
+  whiteSpace: 'nowrap',
+  border: 0,
+};
+
+/**
+ * Live region for backend accessibility announcements.
+ * Announcements already posted natively (macOS) are skipped so
+ * VoiceOver doesn't speak them twice.
+ */
+export function Announcer() {
+  const [message, setMessage] = useState('');
+
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+    let cancelled = false;
+
+    listen<AnnouncementEvent>('accessibility-announcement', (event) => {
+      if (!isValidEvent('accessibility-announcement')) {
+        logger.warn('Invalid event name rejected', { event: 'accessibility-announcement' });
+        return;
+      }
+      if (event.payload.spokenNatively) return;
+      // Clear first so repeating the same message is announced again
+      setMessage('');
+      setTimeout(() => setMessage(event.payload.message), 50);
+    })
+      .then(fn => {
+        if (cancelled) fn();
+        else unlisten = fn;
+      })
+      .catch(error => logger.error(error, { context: 'setup_announcement_listener' }));
+
+    return () => {
+      cancelled = true;
+      unlisten?.();
+    };
+  }, []);
+
+  return (
+    <div role="status" aria-live="polite" aria-atomic="true" style={visuallyHidden}>
+      {message}
+    </div>
+  );
+}
@@ -1,5 +1,7 @@
 import React, { useState } from 'react';
 import { useAuth } from '../contexts/AuthContext';
+import { recordSignIn } from '../lib/session';
+import { logger } from '../lib/logger';
 import './Auth.css';
 
 export const Auth: React.FC = () => {
@@ -44,6 +46,10 @@ export const Auth: React.FC = () => {
     try {
       if (isSignUp) {
         const { error, message, needsEmailConfirmation } = await signUp(email, password);
+        if (!error && !needsEmailConfirmation) {
+          // Email confirmation is off, so signing up also signed the user in
+          await recordSignIn().catch(err => logger.error(err, { context: 'record_sign_in' }));
+        }
         if (error) {
           // Check if rate limited
           if (message?.includes('Too many')) {
@@ -72,6 +78,8 @@ export const Auth: React.FC = () => {
           // Update remaining attempts after failed attempt
           setRemainingAttempts(remainingAuthAttempts(email));
         } else {
+          // Start the session lifetime only for a real credential sign-in
+          await recordSignIn().catch(err => logger.error(err, { context: 'record_sign_in' }));
           // Clear rate limit state on success
           setIsRateLimited(false);
           setRemainingAttempts(null);
@@ -0,0 +1,72 @@
+import React, { useState } from 'react';
+import { unlockApp, LockStatus } from '../lib/appLock';
+import { logger } from '../lib/logger';
+import './Auth.css';
+
+interface LockScreenProps {
+  onUnlock: (status: LockStatus) => void;
+}
+
+export const LockScreen: React.FC<LockScreenProps> = ({ onUnlock }) => {
+  const [passcode, setPasscode] = useState('');
+  const [error, setError] = useState<string | null>(null);
+  const [loading, setLoading] = useState(false);
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!passcode) return;
+
+    setError(null);
+    setLoading(true);
+
+    try {
+      const status = await unlockApp(passcode);
+      setPasscode('');
+      onUnlock(status);
+    } catch (err) {
+      // Backend errors are plain strings ("Incorrect passcode", lockout notice)
+      logger.warn('Unlock attempt failed');
+      setError(typeof err === 'string' ? err : 'Unable to unlock. Please try again.');
+      setPasscode('');
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  return (
+    <div className="auth-container">
+      <div className="auth-card">
+        <h1 className="auth-title">Locked</h1>
+        <p className="auth-subtitle">Enter your passcode to continue</p>
+
+        <form onSubmit={handleSubmit} className="auth-form">
+          <div className="input-group">
+            <input
+              type="password"
+              placeholder="Passcode"
+              value={passcode}
+              onChange={(e) => setPasscode(e.target.value)}
+              className="auth-input"
+              disabled={loading}
+              autoFocus
+            />
+          </div>
+
+          {error && (
+            <div className="auth-message error">
+              {error}
+            </div>
+          )}
+
+          <button
+            type="submit"
+            className="auth-button"
+            disabled={loading || !passcode}
+          >
+            {loading ? 'Unlocking...' : 'Unlock'}
+          </button>
+        </form>
+      </div>
+    </div>
+  );
+};
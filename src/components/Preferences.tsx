@@ -1,11 +1,42 @@
 import React, { useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { message } from '@tauri-apps/plugin-dialog';
+import { relaunch } from '@tauri-apps/plugin-process';
 import { supabase } from '../lib/supabase';
 import { useAuth } from '../contexts/AuthContext';
 import { generateStateToken, storeStateToken } from '../lib/security';
 import { logger, getUserFriendlyMessage } from '../lib/logger';
 import { UpdateButton } from './UpdateManager';
+import { getUpdatePolicy, setUpdatePolicy, UpdatePolicy } from '../lib/updatePolicy';
+import { getSessionPolicy, setSessionPolicy, SessionPolicy } from '../lib/session';
+import { getAppLockStatus, setAppPasscode, clearAppPasscode, setAutoLockTimeout } from '../lib/appLock';
+import './Auth.css';
 import './Preferences.css';
 
+interface AuditCheck {
+  id: string;
+  label: string;
+  status: 'pass' | 'warn' | 'notApplicable';
+  detail: string;
+}
+
+interface WorkSchedule {
+  workDays: number[];
+  startTime: string;
+  endTime: string;
+}
+
+// ISO weekday numbers used by the backend (1 = Monday)
+const WEEKDAYS = [
+  { day: 1, label: 'Mon' },
+  { day: 2, label: 'Tue' },
+  { day: 3, label: 'Wed' },
+  { day: 4, label: 'Thu' },
+  { day: 5, label: 'Fri' },
+  { day: 6, label: 'Sat' },
+  { day: 7, label: 'Sun' },
+];
+
 interface PreferencesProps {
   onClose: () => void;
 }
@@ -16,6 +47,25 @@ const Preferences: React.FC<PreferencesProps> = ({ onClose }) => {
   const [font, setFont] = useState<'system' | 'mono' | 'serif'>('system');
   const [showDeleteConfirm, setShowDeleteConfirm] = useState(false);
   const [resetPasswordSent, setResetPasswordSent] = useState(false);
+  const [lockEnabled, setLockEnabled] = useState(false);
+  const [autoLockMinutes, setAutoLockMinutes] = useState<number | null>(null);
+  const [currentPasscode, setCurrentPasscode] = useState('');
+  const [newPasscode, setNewPasscode] = useState('');
+  const [sessionPolicy, setSessionPolicyState] = useState<SessionPolicy>({ maxAgeDays: null, reauthEveryLaunch: false });
+  const [captureProtected, setCaptureProtected] = useState(false);
+  const [workSchedule, setWorkSchedule] = useState<WorkSchedule | null>(null);
+  const [updatePolicy, setUpdatePolicyState] = useState<UpdatePolicy>('prompt');
+  const [auditChecks, setAuditChecks] = useState<AuditCheck[] | null>(null);
+  const [lockMessage, setLockMessage] = useState<{ text: string; isError: boolean } | null>(null);
+
+  React.useEffect(() => {
+    getAppLockStatus()
+      .then(status => {
+        setLockEnabled(status.enabled);
+        setAutoLockMinutes(status.autoLockMinutes);
+      })
+      .catch(error => logger.error(error, { context: 'load_app_lock_status' }));
+  }, []);
 
   // Load user preferences from database on mount
   React.useEffect(() => {
@@ -104,6 +154,137 @@ const Preferences: React.FC<PreferencesProps> = ({ onClose }) => {
     }
   };
 
+  const handleSetPasscode = async () => {
+    try {
+      const status = await setAppPasscode(newPasscode, lockEnabled ? currentPasscode : undefined);
+      setLockEnabled(status.enabled);
+      setLockMessage({ text: 'Passcode saved. It will be required at launch.', isError: false });
+    } catch (error) {
+      setLockMessage({ text: typeof error === 'string' ? error : getUserFriendlyMessage(error), isError: true });
+    } finally {
+      setCurrentPasscode('');
+      setNewPasscode('');
+    }
+  };
+
+  const handleClearPasscode = async () => {
+    try {
+      const status = await clearAppPasscode(currentPasscode);
+      setLockEnabled(status.enabled);
+      setLockMessage({ text: 'Passcode removed.', isError: false });
+    } catch (error) {
+      setLockMessage({ text: typeof error === 'string' ? error : getUserFriendlyMessage(error), isError: true });
+    } finally {
+      setCurrentPasscode('');
+      setNewPasscode('');
+    }
+  };
+
+  const handleAutoLockChange = async (value: string) => {
+    const minutes = value === 'off' ? null : Number(value);
+    try {
+      const status = await setAutoLockTimeout(minutes);
+      setAutoLockMinutes(status.autoLockMinutes);
+    } catch (error) {
+      setLockMessage({ text: typeof error === 'string' ? error : getUserFriendlyMessage(error), isError: true });
+    }
+  };
+
+  const handleEraseAllData = async () => {
+    try {
+      // The backend shows its own native confirmation dialog
+      const report = await invoke<{ verified: boolean } | null>('erase_all_data');
+      if (!report) return;
+
+      await message(
+        report.verified
+          ? 'All local data was erased. The app will now restart.'
+          : 'Some local data could not be erased. The app will now restart; please try again.',
+        { title: 'Erase All Data', kind: report.verified ? 'info' : 'warning' }
+      );
+      await relaunch();
+    } catch (error) {
+      logger.error(error, { context: 'erase_all_data' });
+      alert(typeof error === 'string' ? error : getUserFriendlyMessage(error));
+    }
+  };
+
+  React.useEffect(() => {
+    getSessionPolicy()
+      .then(setSessionPolicyState)
+      .catch(error => logger.error(error, { context: 'load_session_policy' }));
+  }, []);
+
+  const handleSessionPolicyChange = async (value: string) => {
+    const policy: SessionPolicy = value === 'launch'
+      ? { maxAgeDays: null, reauthEveryLaunch: true }
+      : { maxAgeDays: value === 'never' ? null : Number(value), reauthEveryLaunch: false };
+    try {
+      setSessionPolicyState(await setSessionPolicy(policy));
+    } catch (error) {
+      logger.error(error, { context: 'save_session_policy' });
+    }
+  };
+
+  React.useEffect(() => {
+    invoke<boolean>('get_capture_protection')
+      .then(setCaptureProtected)
+      .catch(error => logger.error(error, { context: 'load_capture_protection' }));
+  }, []);
+
+  React.useEffect(() => {
+    getUpdatePolicy()
+      .then(setUpdatePolicyState)
+      .catch(error => logger.error(error, { context: 'load_update_policy' }));
+  }, []);
+
+  React.useEffect(() => {
+    invoke<WorkSchedule>('get_work_schedule')
+      .then(setWorkSchedule)
+      .catch(error => logger.error(error, { context: 'load_work_schedule' }));
+  }, []);
+
+  const saveWorkSchedule = async (schedule: WorkSchedule) => {
+    try {
+      setWorkSchedule(await invoke<WorkSchedule>('set_work_schedule', { schedule }));
+    } catch (error) {
+      // Invalid combinations (no days, end before start) are rejected by the backend
+      logger.warn('Work schedule rejected', { error: String(error) });
+    }
+  };
+
+  const toggleWorkDay = (day: number) => {
+    if (!workSchedule) return;
+    const workDays = workSchedule.workDays.includes(day)
+      ? workSchedule.workDays.filter(d => d !== day)
+      : [...workSchedule.workDays, day];
+    saveWorkSchedule({ ...workSchedule, workDays });
+  };
+
+  const handleUpdatePolicyChange = async (policy: UpdatePolicy) => {
+    try {
+      setUpdatePolicyState(await setUpdatePolicy(policy));
+    } catch (error) {
+      logger.error(error, { context: 'save_update_policy' });
+    }
+  };
+
+  const handleCaptureProtectionToggle = async () => {
+    try {
+      setCaptureProtected(await invoke<boolean>('set_capture_protection', { enabled: !captureProtected }));
+    } catch (error) {
+      logger.error(error, { context: 'set_capture_protection' });
+    }
+  };
+
+  const handleRunAudit = async () => {
+    try {
+      setAuditChecks(await invoke<AuditCheck[]>('security_audit'));
+    } catch (error) {
+      logger.error(error, { context: 'security_audit' });
+    }
+  };
+
   const handleThemeChange = async (newTheme: 'light' | 'dark') => {
     setTheme(newTheme);
     // Apply theme to root element
@@ -224,6 +405,46 @@ const Preferences: React.FC<PreferencesProps> = ({ onClose }) => {
             </div>
           </section>
 
+          {/* Work Week Section */}
+          {workSchedule && (
+            <section className="preferences-section">
+              <h3 className="section-title">Work Week</h3>
+
+              <div className="preference-item">
+                <label className="preference-label">Work Days</label>
+                <div className="font-selector">
+                  {WEEKDAYS.map(({ day, label }) => (
+                    <button
+                      key={day}
+                      className={`font-option ${workSchedule.workDays.includes(day) ? 'active' : ''}`}
+                      onClick={() => toggleWorkDay(day)}
+                    >
+                      {label}
+                    </button>
+                  ))}
+                </div>
+              </div>
+
+              <div className="preference-item">
+                <label className="preference-label">Work Hours</label>
+                <div className="font-selector">
+                  <input
+                    type="time"
+                    className="auth-input"
+                    value={workSchedule.startTime}
+                    onChange={(e) => saveWorkSchedule({ ...workSchedule, startTime: e.target.value })}
+                  />
+                  <input
+                    type="time"
+                    className="auth-input"
+                    value={workSchedule.endTime}
+                    onChange={(e) => saveWorkSchedule({ ...workSchedule, endTime: e.target.value })}
+                  />
+                </div>
+              </div>
+            </section>
+          )}
+
           {/* Updates Section */}
           <section className="preferences-section">
             <h3 className="section-title">Updates</h3>
@@ -231,6 +452,103 @@ const Preferences: React.FC<PreferencesProps> = ({ onClose }) => {
             <div className="preference-item">
               <UpdateButton showVersion={true} />
             </div>
+
+            <div className="preference-item">
+              <label className="preference-label">When an Update Is Available</label>
+              <select
+                className="auth-input"
+                value={updatePolicy}
+                onChange={(e) => handleUpdatePolicyChange(e.target.value as UpdatePolicy)}
+              >
+                <option value="prompt">Ask me right away</option>
+                <option value="background">Download in the background, then ask to restart</option>
+                <option value="installOnQuit">Install when I quit the app</option>
+              </select>
+            </div>
+          </section>
+
+          {/* Security Section */}
+          <section className="preferences-section">
+            <h3 className="section-title">App Lock</h3>
+
+            {lockEnabled && (
+              <div className="preference-item">
+                <label className="preference-label">Current Passcode</label>
+                <input
+                  type="password"
+                  className="auth-input"
+                  value={currentPasscode}
+                  onChange={(e) => setCurrentPasscode(e.target.value)}
+                />
+              </div>
+            )}
+
+            <div className="preference-item">
+              <label className="preference-label">{lockEnabled ? 'New Passcode' : 'Passcode'}</label>
+              <input
+                type="password"
+                className="auth-input"
+                value={newPasscode}
+                onChange={(e) => setNewPasscode(e.target.value)}
+              />
+            </div>
+
+            <div className="preference-item">
+              <button className="preference-button" onClick={handleSetPasscode} disabled={!newPasscode}>
+                {lockEnabled ? 'Change Passcode' : 'Set Passcode'}
+              </button>
+            </div>
+
+            {lockEnabled && (
+              <div className="preference-item">
+                <label className="preference-label">Lock After Inactivity</label>
+                <select
+                  className="auth-input"
+                  value={autoLockMinutes ?? 'off'}
+                  onChange={(e) => handleAutoLockChange(e.target.value)}
+                >
+                  <option value="off">Never</option>
+                  <option value="1">1 minute</option>
+                  <option value="5">5 minutes</option>
+                  <option value="15">15 minutes</option>
+                  <option value="60">1 hour</option>
+                </select>
+              </div>
+            )}
+
+            {lockEnabled && (
+              <div className="preference-item">
+                <button className="preference-button" onClick={handleClearPasscode} disabled={!currentPasscode}>
+                  Remove Passcode
+                </button>
+              </div>
+            )}
+
+            {lockMessage && (
+              <div className={lockMessage.isError ? 'warning-message' : 'success-message'}>
+                {lockMessage.text}
+              </div>
+            )}
+
+            <div className="preference-item">
+              <button className="preference-button" onClick={handleCaptureProtectionToggle}>
+                {captureProtected ? 'Allow Screen Capture' : 'Hide Windows From Screen Capture'}
+              </button>
+            </div>
+
+            <div className="preference-item">
+              <button className="preference-button" onClick={handleRunAudit}>
+                Run Security Audit
+              </button>
+              {auditChecks?.map(check => (
+                <div
+                  key={check.id}
+                  className={check.status === 'warn' ? 'warning-message' : 'success-message'}
+                >
+                  {check.status === 'pass' ? '✓' : check.status === 'warn' ? '!' : '–'} {check.label}: {check.detail}
+                </div>
+              ))}
+            </div>
           </section>
 
           {/* Account Section */}
@@ -242,6 +560,20 @@ const Preferences: React.FC<PreferencesProps> = ({ onClose }) => {
               <div className="account-email">{user?.email}</div>
             </div>
 
+            <div className="preference-item">
+              <label className="preference-label">Require Sign-In</label>
+              <select
+                className="auth-input"
+                value={sessionPolicy.reauthEveryLaunch ? 'launch' : (sessionPolicy.maxAgeDays ?? 'never')}
+                onChange={(e) => handleSessionPolicyChange(e.target.value)}
+              >
+                <option value="never">Never</option>
+                <option value="7">Every 7 days</option>
+                <option value="30">Every 30 days</option>
+                <option value="launch">Every launch</option>
+              </select>
+            </div>
+
             <div className="preference-item">
               <button className="preference-button" onClick={handleResetPassword}>
                 <svg width="20" height="20" viewBox="0 0 20 20" fill="none">
@@ -287,6 +619,12 @@ const Preferences: React.FC<PreferencesProps> = ({ onClose }) => {
                 </div>
               )}
             </div>
+
+            <div className="preference-item">
+              <button className="preference-button danger-button" onClick={handleEraseAllData}>
+                Erase All Local Data
+              </button>
+            </div>
           </section>
         </div>
       </div>
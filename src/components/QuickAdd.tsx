@@ -0,0 +1,73 @@
+import React, { useEffect, useRef, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { getCurrentWindow } from '@tauri-apps/api/window';
+import { logger } from '../lib/logger';
+import './QuickAdd.css';
+
+/**
+ * Quick-capture window opened from the tray menu or Cmd/Ctrl+Shift+T.
+ * Submitted text is saved by the backend as a task due today.
+ */
+export function QuickAdd() {
+  const [title, setTitle] = useState('');
+  const [error, setError] = useState<string | null>(null);
+  const [saving, setSaving] = useState(false);
+  const inputRef = useRef<HTMLInputElement>(null);
+
+  // The window is reused; refocus the input whenever it is shown again
+  useEffect(() => {
+    const handleFocus = () => inputRef.current?.focus();
+    window.addEventListener('focus', handleFocus);
+    return () => window.removeEventListener('focus', handleFocus);
+  }, []);
+
+  const hide = () => {
+    setError(null);
+    getCurrentWindow()
+      .hide()
+      .catch(err => logger.error(err, { context: 'hide_quick_add' }));
+  };
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!title.trim() || saving) return;
+
+    setError(null);
+    setSaving(true);
+    try {
+      await invoke('quick_add_task', { title });
+      setTitle('');
+    } catch (err) {
+      // Backend errors are plain strings ("App is locked", validation messages)
+      logger.error(err, { context: 'quick_add_task' });
+      setError(typeof err === 'string' ? err : 'Unable to save the task.');
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <form
+      className="quick-add"
+      onSubmit={handleSubmit}
+      onKeyDown={(e) => {
+        if (e.key === 'Escape') hide();
+      }}
+    >
+      <input
+        ref={inputRef}
+        type="text"
+        value={title}
+        onChange={(e) => setTitle(e.target.value)}
+        placeholder="Add a task for today…"
+        className="quick-add-input"
+        aria-label="New task"
+        disabled={saving}
+        autoFocus
+      />
+      <div className={`quick-add-hint ${error ? 'error' : ''}`} role={error ? 'alert' : undefined}>
+        {error ?? 'Enter to save · Esc to close'}
+      </div>
+    </form>
+  );
+}
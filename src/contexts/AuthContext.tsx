@@ -8,6 +8,10 @@ import {
   formatTimeRemaining
 } from '../lib/security';
 import { logger } from '../lib/logger';
+import { recordSignOut, isReauthRequired } from '../lib/session';
+import { clearLocalTasks } from '../lib/tasks';
+import { listen } from '@tauri-apps/api/event';
+import { isValidEvent } from '../lib/events';
 
 interface AuthContextType {
   user: User | null;
@@ -24,23 +28,52 @@ const AuthContext = createContext<AuthContextType | undefined>(undefined);
 // Initialize rate limiter at module level
 const rateLimiter = new RateLimiter();
 
+// Whether the backend session policy requires signing in again. Fails closed:
+// if the check itself fails, the session is not kept.
+const sessionExpired = async (): Promise<boolean> => {
+  try {
+    return await isReauthRequired();
+  } catch (error) {
+    logger.error(error, { context: 'check_session' });
+    return true;
+  }
+};
+
 export const AuthProvider: React.FC<{ children: React.ReactNode }> = ({ children }) => {
   const [user, setUser] = useState<User | null>(null);
   const [session, setSession] = useState<Session | null>(null);
   const [loading, setLoading] = useState(true);
 
   useEffect(() => {
-    // Get initial session
-    supabase.auth.getSession().then(({ data: { session } }) => {
-      setSession(session);
-      setUser(session?.user ?? null);
+    // Get initial session, then let the backend decide whether it is still valid
+    supabase.auth.getSession().then(async ({ data: { session } }) => {
+      let activeSession = session;
+
+      if (activeSession && await sessionExpired()) {
+        logger.info('Session expired by policy, signing out');
+        await supabase.auth.signOut();
+        activeSession = null;
+      }
+
+      setSession(activeSession);
+      setUser(activeSession?.user ?? null);
       setLoading(false);
     });
 
-    // Listen for auth changes
+    // Listen for auth changes. The restored session is handled above so an
+    // expired session is never briefly shown. Sign-ins are recorded by the
+    // sign-in form: supabase-js also emits SIGNED_IN when it recovers a
+    // session, which must not extend the session lifetime.
     const {
       data: { subscription },
-    } = supabase.auth.onAuthStateChange((_event, session) => {
+    } = supabase.auth.onAuthStateChange((event, session) => {
+      if (event === 'INITIAL_SESSION') return;
+
+      if (event === 'SIGNED_OUT') {
+        recordSignOut().catch(error => logger.error(error, { context: 'record_sign_out' }));
+        clearLocalTasks().catch(error => logger.error(error, { context: 'clear_local_tasks' }));
+      }
+
       setSession(session);
       setUser(session?.user ?? null);
       setLoading(false);
@@ -49,6 +82,47 @@ export const AuthProvider: React.FC<{ children: React.ReactNode }> = ({ children
     return () => subscription.unsubscribe();
   }, []);
 
+  // A long-running app is re-checked against the session policy after sleep
+  // and at midnight, not only at launch
+  useEffect(() => {
+    const unlisteners: Array<() => void> = [];
+    let cancelled = false;
+
+    const recheck = async () => {
+      const { data: { session } } = await supabase.auth.getSession();
+      if (session && await sessionExpired()) {
+        logger.info('Session expired by policy, signing out');
+        await supabase.auth.signOut();
+      }
+    };
+
+    (async () => {
+      try {
+        for (const eventName of ['system-resumed', 'day-changed'] as const) {
+          const unlisten = await listen(eventName, () => {
+            if (!isValidEvent(eventName)) {
+              logger.warn('Invalid event name rejected', { event: eventName });
+              return;
+            }
+            recheck().catch(error => logger.error(error, { context: 'recheck_session' }));
+          });
+          if (cancelled) {
+            unlisten();
+          } else {
+            unlisteners.push(unlisten);
+          }
+        }
+      } catch (error) {
+        logger.error(error, { context: 'setup_session_listeners' });
+      }
+    })();
+
+    return () => {
+      cancelled = true;
+      unlisteners.forEach(unlisten => unlisten());
+    };
+  }, []);
+
   const signIn = async (email: string, password: string) => {
     // Check rate limit before attempting sign in
     const { allowed, retryAfter } = rateLimiter.checkLimit(email);
@@ -0,0 +1,29 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/**
+ * App lock status as reported by the Rust backend.
+ * The backend owns the lock state; the webview only renders it.
+ */
+export interface LockStatus {
+  enabled: boolean;
+  locked: boolean;
+  retryAfterSecs: number;
+  autoLockMinutes: number | null;
+}
+
+export const getAppLockStatus = () => invoke<LockStatus>('get_app_lock_status');
+
+export const unlockApp = (passcode: string) => invoke<LockStatus>('unlock_app', { passcode });
+
+export const lockApp = () => invoke<LockStatus>('lock_app');
+
+export const setAppPasscode = (passcode: string, current?: string) =>
+  invoke<LockStatus>('set_app_passcode', { current: current ?? null, passcode });
+
+export const clearAppPasscode = (current: string) =>
+  invoke<LockStatus>('clear_app_passcode', { current });
+
+export const reportActivity = () => invoke<void>('report_activity');
+
+export const setAutoLockTimeout = (minutes: number | null) =>
+  invoke<LockStatus>('set_auto_lock_timeout', { minutes });
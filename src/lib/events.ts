@@ -0,0 +1,22 @@
+// Allowed event names for IPC validation. Every event the backend emits to
+// the webview is listed here and in capabilities/default.md.
+export const ALLOWED_EVENTS = [
+  'sign-out-user',
+  'navigate-to-preferences',
+  'system-resumed',
+  'day-changed',
+  'tasks-changed',
+  'app-locked',
+  'app-lock-changed',
+  'update-available',
+  'update-ready',
+  'accessibility-announcement',
+  'reminder-fired',
+] as const;
+
+export type AllowedEvent = typeof ALLOWED_EVENTS[number];
+
+// Validates that an event name is in the allowlist
+export const isValidEvent = (eventName: string): eventName is AllowedEvent => {
+  return (ALLOWED_EVENTS as readonly string[]).includes(eventName);
+};
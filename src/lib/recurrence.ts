@@ -0,0 +1,33 @@
+import { invoke } from '@tauri-apps/api/core';
+
+export type Frequency = 'daily' | 'weekly' | 'monthly' | 'yearly';
+
+export interface WeekdaySpec {
+  /** ISO weekday, 1 = Monday … 7 = Sunday */
+  weekday: number;
+  /** 2 = second, -1 = last; null for every matching weekday */
+  ordinal: number | null;
+}
+
+/** Internal recurrence rule, a subset of RFC 5545 RRULE */
+export interface RecurrenceRule {
+  freq: Frequency;
+  interval: number;
+  byDay: WeekdaySpec[];
+  byMonthDay: number[];
+  bySetPos: number | null;
+  afterCompletion: boolean;
+}
+
+export interface RecurrencePreview {
+  rule: RecurrenceRule;
+  rrule: string;
+  /** Next occurrences as YYYY-MM-DD */
+  nextOccurrences: string[];
+}
+
+export const parseRecurrence = (phrase: string) =>
+  invoke<RecurrenceRule>('parse_recurrence', { phrase });
+
+export const previewRecurrence = (phrase: string, from?: string) =>
+  invoke<RecurrencePreview>('preview_recurrence', { phrase, from: from ?? null });
@@ -0,0 +1,33 @@
+import { invoke } from '@tauri-apps/api/core';
+
+export type ReminderRepeat = 'daily' | 'weekly';
+
+/**
+ * A task's reminder, kept in the local task store; times are Unix seconds.
+ * Setting a due date schedules one for that morning automatically.
+ */
+export interface Reminder {
+  id: string;
+  taskId: string;
+  /** The task's current title */
+  title: string;
+  fireAt: number;
+  repeat: ReminderRepeat | null;
+  snoozedUntil: number | null;
+  delivered: boolean;
+}
+
+export const listReminders = () => invoke<Reminder[]>('list_reminders');
+
+/** Moves a task's reminder to another time */
+export const scheduleReminder = (taskId: string, fireAt: Date, repeat: ReminderRepeat | null = null) =>
+  invoke<Reminder>('schedule_reminder', {
+    taskId,
+    fireAt: Math.floor(fireAt.getTime() / 1000),
+    repeat,
+  });
+
+export const cancelReminder = (id: string) => invoke<void>('cancel_reminder', { id });
+
+export const snoozeReminder = (id: string, minutes?: number) =>
+  invoke<Reminder>('snooze_reminder', { id, minutes: minutes ?? null });
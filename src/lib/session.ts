@@ -0,0 +1,21 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/**
+ * Session lifetime policy enforced by the Rust backend.
+ * `maxAgeDays: null` means sessions don't expire by age.
+ */
+export interface SessionPolicy {
+  maxAgeDays: number | null;
+  reauthEveryLaunch: boolean;
+}
+
+export const recordSignIn = () => invoke<void>('record_sign_in');
+
+export const recordSignOut = () => invoke<void>('record_sign_out');
+
+export const isReauthRequired = () => invoke<boolean>('check_session');
+
+export const getSessionPolicy = () => invoke<SessionPolicy>('get_session_policy');
+
+export const setSessionPolicy = (policy: SessionPolicy) =>
+  invoke<SessionPolicy>('set_session_policy', { policy });
@@ -0,0 +1,107 @@
+import { invoke } from '@tauri-apps/api/core';
+import { supabase } from './supabase';
+import { logger } from './logger';
+
+/** A local change not yet pushed to Supabase; times are Unix seconds */
+interface PendingChange {
+  id: string;
+  title: string;
+  completedAt: number | null;
+  createdAt: number;
+  /** Local version of the row, echoed back once the push succeeds */
+  revision: number;
+  deleted: boolean;
+}
+
+interface RemoteTask {
+  id: string;
+  name: string;
+  createdAt: number;
+  completedAt: number | null;
+  deletedAt: number | null;
+}
+
+const toIso = (seconds: number) => new Date(seconds * 1000).toISOString();
+const toSeconds = (iso: string | null) => (iso ? Math.floor(new Date(iso).getTime() / 1000) : null);
+
+let inFlight: Promise<void> | null = null;
+let rerun = false;
+
+/**
+ * Mirrors the local task store to Supabase. Local changes are pushed first,
+ * then the user's rows are pulled so changes from other devices show up.
+ * A change that fails to push stays pending and is retried on the next sync.
+ * A sync requested while one is running runs once more after it, so changes
+ * made after the running sync read its pending list are still pushed.
+ */
+export function syncTasks(userId: string): Promise<void> {
+  if (inFlight) {
+    rerun = true;
+    return inFlight;
+  }
+
+  inFlight = (async () => {
+    try {
+      do {
+        rerun = false;
+        await runSync(userId);
+      } while (rerun);
+    } finally {
+      inFlight = null;
+      rerun = false;
+    }
+  })();
+  return inFlight;
+}
+
+async function runSync(userId: string): Promise<void> {
+  // Claims the local store for this user first, dropping another account's tasks
+  const pending = await invoke<PendingChange[]>('pending_task_changes', { userId });
+  const synced: Array<{ id: string; revision: number }> = [];
+
+  for (const change of pending) {
+    // Completing a task also soft-deletes it, matching the table's constraints
+    const completedAt = change.completedAt === null ? null : toIso(change.completedAt);
+    const { error } = change.deleted
+      ? await supabase.from('tasks').delete().eq('id', change.id).eq('user_id', userId)
+      : await supabase.from('tasks').upsert({
+          id: change.id,
+          user_id: userId,
+          name: change.title,
+          created_at: toIso(change.createdAt),
+          completed_at: completedAt,
+          deleted_at: completedAt,
+        });
+
+    if (error) {
+      logger.warn('Task change not synced', { context: 'sync_tasks_push', code: error.code });
+      continue;
+    }
+    synced.push({ id: change.id, revision: change.revision });
+  }
+
+  if (synced.length > 0) {
+    await invoke('mark_tasks_synced', { changes: synced });
+  }
+
+  // Removes completed tasks older than the retention window
+  const { error: cleanupError } = await supabase.rpc('cleanup_tasks');
+  if (cleanupError) {
+    logger.error(cleanupError, { context: 'cleanup_tasks' });
+  }
+
+  const { data, error } = await supabase
+    .from('tasks')
+    .select('id, name, created_at, completed_at, deleted_at')
+    .eq('user_id', userId);
+  if (error) throw error;
+
+  const tasks: RemoteTask[] = (data ?? []).map(task => ({
+    id: task.id,
+    name: task.name,
+    createdAt: toSeconds(task.created_at) ?? 0,
+    completedAt: toSeconds(task.completed_at),
+    deletedAt: toSeconds(task.deleted_at),
+  }));
+  await invoke('merge_remote_tasks', { userId, tasks });
+}
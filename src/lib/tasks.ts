@@ -0,0 +1,48 @@
+import { invoke } from '@tauri-apps/api/core';
+
+export type TaskPriority = 'none' | 'low' | 'medium' | 'high';
+
+/** A task in the local SQLite store owned by the Rust backend */
+export interface LocalTask {
+  id: string;
+  title: string;
+  notes: string | null;
+  /** YYYY-MM-DD */
+  dueDate: string | null;
+  completed: boolean;
+  priority: TaskPriority;
+  tags: string[];
+  /** Unix seconds */
+  completedAt: number | null;
+  createdAt: number;
+  updatedAt: number;
+}
+
+export interface TaskInput {
+  title: string;
+  notes?: string | null;
+  dueDate?: string | null;
+  priority?: TaskPriority;
+  tags?: string[];
+}
+
+export const listTasks = () => invoke<LocalTask[]>('list_tasks');
+
+export const createTask = (task: TaskInput) => invoke<LocalTask>('create_task', { task });
+
+export const updateTask = (id: string, task: TaskInput) =>
+  invoke<LocalTask>('update_task', { id, task });
+
+export const deleteTask = (id: string) => invoke<void>('delete_task', { id });
+
+export const toggleComplete = (id: string) => invoke<LocalTask>('toggle_complete', { id });
+
+/** Removes the signed-out account's tasks from this device */
+export const clearLocalTasks = () => invoke<void>('clear_local_tasks');
+
+/** Local calendar date as YYYY-MM-DD, the format used for due dates */
+export const localDateKey = (date: Date = new Date()) => {
+  const month = String(date.getMonth() + 1).padStart(2, '0');
+  const day = String(date.getDate()).padStart(2, '0');
+  return `${date.getFullYear()}-${month}-${day}`;
+};
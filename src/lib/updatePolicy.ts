@@ -0,0 +1,23 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/**
+ * Update delivery policy, enforced by the Rust backend's update checker.
+ * - prompt: ask as soon as an update is found
+ * - background: download silently, then ask once to restart
+ * - installOnQuit: download silently and install when the app quits
+ */
+export type UpdatePolicy = 'prompt' | 'background' | 'installOnQuit';
+
+export interface UpdateNotice {
+  version: string;
+  notes: string;
+}
+
+export const getUpdatePolicy = () => invoke<UpdatePolicy>('get_update_policy');
+
+export const setUpdatePolicy = (policy: UpdatePolicy) =>
+  invoke<UpdatePolicy>('set_update_policy', { policy });
+
+export const deferUpdate = () => invoke<void>('defer_update');
+
+export const installUpdateNow = () => invoke<void>('install_update_now');
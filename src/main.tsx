@@ -5,12 +5,30 @@ import App from './App.tsx'
 import { AuthProvider, useAuth } from './contexts/AuthContext'
 import { Auth } from './components/Auth'
 import { LoadingScreen } from './components/LoadingScreen'
+import { LockScreen } from './components/LockScreen'
+import { Announcer } from './components/Announcer'
+import { QuickAdd } from './components/QuickAdd'
 import { onOpenUrl } from '@tauri-apps/plugin-deep-link'
 import { supabase } from './lib/supabase'
 import { validateDeepLinkUrl, validateStateToken, DeepLinkReasonCode } from './lib/security'
 import ErrorBoundary from './components/ErrorBoundary'
 import { logger, getUserFriendlyMessage, initErrorTracking } from './lib/logger'
-import { useUpdater } from './hooks/useUpdater'
+import { ask } from '@tauri-apps/plugin-dialog'
+import { deferUpdate, installUpdateNow, UpdateNotice } from './lib/updatePolicy'
+import { getAppLockStatus, reportActivity, LockStatus } from './lib/appLock'
+import { recordSignIn } from './lib/session'
+import { listen } from '@tauri-apps/api/event'
+import { isValidEvent } from './lib/events'
+import { invoke } from '@tauri-apps/api/core'
+import { getCurrentWindow } from '@tauri-apps/api/window'
+
+// Mirror the layout for right-to-left system locales (Arabic, Hebrew, ...)
+invoke<{ locale: string; direction: 'ltr' | 'rtl' }>('get_layout_direction')
+  .then(({ locale, direction }) => {
+    document.documentElement.setAttribute('dir', direction);
+    document.documentElement.setAttribute('lang', locale);
+  })
+  .catch(error => logger.error(error, { context: 'get_layout_direction' }));
 
 // Initialize error tracking for production if DSN is provided
 if (import.meta.env.VITE_SENTRY_DSN) {
@@ -25,13 +43,135 @@ function AppWrapper() {
   const [passwordResetMode, setPasswordResetMode] = useState(false);
   const [newPassword, setNewPassword] = useState('');
   const [passwordResetError, setPasswordResetError] = useState('');
+  const [lockStatus, setLockStatus] = useState<LockStatus | null>(null);
 
-  // Auto-check for updates on startup and periodically
-  useUpdater({
-    checkOnMount: true,
-    autoCheckInterval: 10 * 60 * 1000, // Check every 10 minutes
-    silentCheck: true
-  });
+  // The backend decides whether a passcode is required at launch
+  useEffect(() => {
+    getAppLockStatus()
+      .then(setLockStatus)
+      .catch((error) => {
+        // Fail closed: if the lock state can't be read, keep the app locked
+        logger.error(error, { context: 'get_app_lock_status' });
+        setLockStatus({ enabled: true, locked: true, retryAfterSecs: 0, autoLockMinutes: null });
+      });
+  }, []);
+
+  // The backend idle timer locks the app and tells us to blank the view;
+  // lock settings changed in Preferences arrive as a full status, so the
+  // activity reporter below starts as soon as auto-lock is turned on
+  useEffect(() => {
+    const unlisteners: Array<() => void> = [];
+    let cancelled = false;
+
+    (async () => {
+      try {
+        const listeners = [
+          await listen('app-locked', () => {
+            if (!isValidEvent('app-locked')) {
+              logger.warn('Invalid event name rejected', { event: 'app-locked' });
+              return;
+            }
+            setLockStatus(prev => prev ? { ...prev, locked: true } : prev);
+          }),
+          await listen<LockStatus>('app-lock-changed', (event) => {
+            if (!isValidEvent('app-lock-changed')) {
+              logger.warn('Invalid event name rejected', { event: 'app-lock-changed' });
+              return;
+            }
+            setLockStatus(event.payload);
+          }),
+        ];
+        for (const unlisten of listeners) {
+          if (cancelled) {
+            unlisten();
+          } else {
+            unlisteners.push(unlisten);
+          }
+        }
+      } catch (error) {
+        logger.error(error, { context: 'setup_app_lock_listeners' });
+      }
+    })();
+
+    return () => {
+      cancelled = true;
+      unlisteners.forEach(unlisten => unlisten());
+    };
+  }, []);
+
+  // Report user activity to the backend idle timer, at most every 30 seconds
+  useEffect(() => {
+    if (!lockStatus?.autoLockMinutes || lockStatus.locked) return;
+
+    let lastReport = 0;
+    const handleActivity = () => {
+      const now = Date.now();
+      if (now - lastReport < 30 * 1000) return;
+      lastReport = now;
+      reportActivity().catch(error => logger.error(error, { context: 'report_activity' }));
+    };
+
+    const events = ['mousemove', 'mousedown', 'keydown', 'wheel'] as const;
+    events.forEach(event => window.addEventListener(event, handleActivity, { passive: true }));
+    return () => events.forEach(event => window.removeEventListener(event, handleActivity));
+  }, [lockStatus]);
+
+  // The backend checks for updates on a schedule and applies the user's
+  // update policy; we only render the prompts it asks for
+  useEffect(() => {
+    const unlisteners: Array<() => void> = [];
+    let cancelled = false;
+
+    const promptForUpdate = async (notice: UpdateNotice, downloaded: boolean) => {
+      const install = await ask(
+        downloaded
+          ? `Version ${notice.version} has been downloaded and is ready to install.\n\nRelease notes:\n${notice.notes}`
+          : `Install version ${notice.version}?\n\nThe app will restart after installation.\n\nRelease notes:\n${notice.notes}`,
+        {
+          title: 'Update Available',
+          kind: 'info',
+          okLabel: 'Restart & Install',
+          cancelLabel: 'Remind Me Tomorrow',
+        }
+      );
+
+      try {
+        if (install) {
+          await installUpdateNow();
+        } else {
+          await deferUpdate();
+        }
+      } catch (error) {
+        logger.error(error, { context: 'apply_update_choice' });
+      }
+    };
+
+    (async () => {
+      try {
+        for (const [eventName, downloaded] of [['update-available', false], ['update-ready', true]] as const) {
+          const unlisten = await listen<UpdateNotice>(eventName, (event) => {
+            if (!isValidEvent(eventName)) {
+              logger.warn('Invalid event name rejected', { event: eventName });
+              return;
+            }
+            promptForUpdate(event.payload, downloaded);
+          });
+          if (cancelled) {
+            unlisten();
+          } else {
+            unlisteners.push(unlisten);
+          }
+        }
+      } catch (error) {
+        logger.error(error, { context: 'setup_update_listeners' });
+      }
+    })();
+
+    return () => {
+      cancelled = true;
+      unlisteners.forEach(unlisten => unlisten());
+    };
+  }, []);
 
   useEffect(() => {
     // Set up deep link listener for email confirmation and password reset
@@ -87,6 +227,9 @@ function AppWrapper() {
                   if (error) {
                     logger.error(error, { context: 'email_confirmation_callback' });
                     alert(getUserFriendlyMessage(error));
+                  } else {
+                    // The one-time confirmation link is a real sign-in
+                    recordSignIn().catch(err => logger.error(err, { context: 'record_sign_in' }));
                   }
                 });
               }
@@ -164,10 +307,14 @@ function AppWrapper() {
     }
   };
 
-  if (loading) {
+  if (loading || !lockStatus) {
     return <LoadingScreen />;
   }
 
+  if (lockStatus.locked) {
+    return <LockScreen onUnlock={setLockStatus} />;
+  }
+
   // Show password reset UI if in password reset mode
   if (passwordResetMode) {
     return (
@@ -231,58 +378,73 @@ function AppWrapper() {
   return user ? <App /> : <Auth />;
 }
 
-createRoot(document.getElementById('root')!).render(
-  <StrictMode>
-    <ErrorBoundary
-      fallback={(error, reset) => (
-        <div style={{ 
-          minHeight: '100vh',
-          display: 'flex',
-          alignItems: 'center',
-          justifyContent: 'center',
-          background: 'linear-gradient(135deg, #667eea 0%, #764ba2 100%)',
-          padding: '20px'
-        }}>
-          <div style={{
-            background: 'rgba(255, 255, 255, 0.95)',
-            borderRadius: '16px',
-            padding: '40px',
-            maxWidth: '500px',
-            width: '100%',
-            boxShadow: '0 20px 60px rgba(0, 0, 0, 0.3)',
-            textAlign: 'center'
+// The quick-capture window only renders its input; everything else
+// (auth, lock screen, updates) belongs to the main window
+const isQuickAdd = getCurrentWindow().label === 'quick-add'
+
+const root = createRoot(document.getElementById('root')!)
+
+if (isQuickAdd) {
+  root.render(
+    <StrictMode>
+      <QuickAdd />
+    </StrictMode>,
+  )
+} else {
+  root.render(
+    <StrictMode>
+      <ErrorBoundary
+        fallback={(error, reset) => (
+          <div style={{ 
+            minHeight: '100vh',
+            display: 'flex',
+            alignItems: 'center',
+            justifyContent: 'center',
+            background: 'linear-gradient(135deg, #667eea 0%, #764ba2 100%)',
+            padding: '20px'
           }}>
-            <div style={{ fontSize: '48px', marginBottom: '20px' }}>⚠️</div>
-            <h1 style={{ fontSize: '24px', fontWeight: '600', color: '#1a202c', marginBottom: '16px' }}>
-              Something went wrong
-            </h1>
-            <p style={{ fontSize: '16px', color: '#4a5568', marginBottom: '24px', lineHeight: '1.5' }}>
-              {getUserFriendlyMessage(error)}
-            </p>
-            <button
-              onClick={reset}
-              style={{
-                background: 'linear-gradient(135deg, #667eea 0%, #764ba2 100%)',
-                color: 'white',
-                border: 'none',
-                borderRadius: '8px',
-                padding: '12px 32px',
-                fontSize: '16px',
-                fontWeight: '600',
-                cursor: 'pointer',
-                boxShadow: '0 4px 12px rgba(102, 126, 234, 0.4)'
-              }}
-            >
-              Try Again
-            </button>
+            <div style={{
+              background: 'rgba(255, 255, 255, 0.95)',
+              borderRadius: '16px',
+              padding: '40px',
+              maxWidth: '500px',
+              width: '100%',
+              boxShadow: '0 20px 60px rgba(0, 0, 0, 0.3)',
+              textAlign: 'center'
+            }}>
+              <div style={{ fontSize: '48px', marginBottom: '20px' }}>⚠️</div>
+              <h1 style={{ fontSize: '24px', fontWeight: '600', color: '#1a202c', marginBottom: '16px' }}>
+                Something went wrong
+              </h1>
+              <p style={{ fontSize: '16px', color: '#4a5568', marginBottom: '24px', lineHeight: '1.5' }}>
+                {getUserFriendlyMessage(error)}
+              </p>
+              <button
+                onClick={reset}
+                style={{
+                  background: 'linear-gradient(135deg, #667eea 0%, #764ba2 100%)',
+                  color: 'white',
+                  border: 'none',
+                  borderRadius: '8px',
+                  padding: '12px 32px',
+                  fontSize: '16px',
+                  fontWeight: '600',
+                  cursor: 'pointer',
+                  boxShadow: '0 4px 12px rgba(102, 126, 234, 0.4)'
+                }}
+              >
+                Try Again
+              </button>
+            </div>
           </div>
-        </div>
-      )}
-      onError={(error, errorInfo) => logger.error(error, { errorInfo })}
-    >
-      <AuthProvider>
-        <AppWrapper />
-      </AuthProvider>
-    </ErrorBoundary>
-  </StrictMode>,
-)
+        )}
+        onError={(error, errorInfo) => logger.error(error, { errorInfo })}
+      >
+        <AuthProvider>
+          <AppWrapper />
+        </AuthProvider>
+        <Announcer />
+      </ErrorBoundary>
+    </StrictMode>,
+  )
+}